@@ -1,5 +1,8 @@
 use bevy::{asset::AssetPath, prelude::*};
-use bevy_editor_pls::{extensions::EditorExtensionSpawn, EditorPlugin, EditorSettings};
+use bevy_editor_pls::{
+    extensions::{EditorExtensionAssets, EditorExtensionMaterial, EditorExtensionSpawn},
+    EditorPlugin, EditorSettings,
+};
 
 fn main() {
     App::build()
@@ -24,6 +27,8 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugin(EditorPlugin)
         .add_plugin(EditorExtensionSpawn)
+        .add_plugin(EditorExtensionAssets)
+        .add_plugin(EditorExtensionMaterial)
         .add_startup_system(setup.system())
         .run();
 }
@@ -2,23 +2,56 @@ use bevy::prelude::*;
 use bevy_input_actionmap::InputMap;
 use bevy_inspector_egui::WorldInspectorParams;
 
-use crate::{ui::EditorMenuEvent, EditorSettings};
+use crate::{plugin::EditorState, ui::EditorMenuEvent, EditorSettings, EditorTime, PlayMode, ViewPreset};
 
 #[derive(Hash, PartialEq, Eq, Clone)]
 pub enum EditorAction {
     ToggleWorldInspector,
     ToggleClickToInspect,
     ToggleWireframes,
+    /// Toggles the wireframe of the currently inspected entity and the meshes among its descendants
+    ToggleWireframeSelection,
+    ToggleGrid,
+    ToggleWorldAxes,
     ToggleFlycam,
+    ToggleEditorCamera,
+    ToggleFollowSelected,
+    /// Locks and hides the cursor so that moving the mouse rotates the fly camera. Holding the right mouse button does the same.
+    ToggleMouseLook,
+    /// Smoothly moves the editor camera to an axis-aligned view around its pivot
+    SetView(ViewPreset),
+    /// Switches the editor camera between a perspective and an orthographic projection
+    ToggleOrthographic,
+    /// Renders the viewport from the currently inspected camera
+    LookThroughSelected,
+    /// Renders the viewport from the editor camera again after [`EditorAction::LookThroughSelected`]
+    ReturnToEditorCamera,
+    /// Moves the currently inspected camera to the pose of the camera in the viewport
+    AlignSelectedToView,
+    /// Smoothly moves 2D cameras back to where they were before being panned and zoomed
+    Reset2dView,
     TogglePerformancePanel,
     ToggleEditorUi,
+    TogglePlayMode,
+    PauseGame,
+    ResumeGame,
+    StepFrame,
+    HalveTimeScale,
+    DoubleTimeScale,
+    /// Saves the pose of the editor camera into bookmark slot `1..=9`
+    SaveCameraBookmark(u8),
+    /// Smoothly moves the editor camera to the pose saved in bookmark slot `1..=9`
+    LoadCameraBookmark(u8),
 }
 
 pub(crate) fn action_system(
     input: Res<InputMap<EditorAction>>,
     mut settings: ResMut<EditorSettings>,
+    editor_state: Res<EditorState>,
     mut editor_events: EventWriter<EditorMenuEvent>,
     mut world_inspector_params: ResMut<WorldInspectorParams>,
+    mut editor_time: ResMut<EditorTime>,
+    mut play_mode: ResMut<PlayMode>,
 ) {
     if input.just_active(EditorAction::ToggleClickToInspect) {
         settings.click_to_inspect = !settings.click_to_inspect;
@@ -26,10 +59,27 @@ pub(crate) fn action_system(
     if input.just_active(EditorAction::ToggleWireframes) {
         settings.show_wireframes = !settings.show_wireframes;
     }
+    if input.just_active(EditorAction::ToggleWireframeSelection) {
+        if let Some(entity) = editor_state.currently_inspected {
+            editor_events.send(EditorMenuEvent::ToggleWireframe(entity));
+        }
+    }
+    if input.just_active(EditorAction::ToggleGrid) {
+        settings.show_grid = !settings.show_grid;
+    }
+    if input.just_active(EditorAction::ToggleWorldAxes) {
+        settings.show_world_axes = !settings.show_world_axes;
+    }
     if input.just_active(EditorAction::ToggleFlycam) {
         settings.fly_camera = !settings.fly_camera;
         editor_events.send(EditorMenuEvent::EnableFlyCams(settings.fly_camera));
     }
+    if input.just_active(EditorAction::ToggleEditorCamera) {
+        settings.editor_camera = !settings.editor_camera;
+    }
+    if input.just_active(EditorAction::ToggleFollowSelected) {
+        settings.follow_selected = !settings.follow_selected;
+    }
     if input.just_active(EditorAction::TogglePerformancePanel) {
         settings.performance_panel = !settings.performance_panel;
     }
@@ -39,4 +89,25 @@ pub(crate) fn action_system(
     if input.just_active(EditorAction::ToggleEditorUi) {
         settings.display_ui = !settings.display_ui;
     }
+    if input.just_active(EditorAction::TogglePlayMode) {
+        play_mode.toggle();
+    }
+    if input.just_active(EditorAction::PauseGame) {
+        editor_time.pause();
+    }
+    if input.just_active(EditorAction::ResumeGame) {
+        editor_time.resume();
+    }
+    if input.just_active(EditorAction::StepFrame) {
+        editor_time.step();
+    }
+    if input.just_active(EditorAction::HalveTimeScale) {
+        let time_scale = editor_time.time_scale();
+        editor_time.set_time_scale(time_scale / 2.0);
+    }
+    if input.just_active(EditorAction::DoubleTimeScale) {
+        // doubling a scale of 0 would get stuck there
+        let time_scale = editor_time.time_scale().max(1.0 / 16.0);
+        editor_time.set_time_scale(time_scale * 2.0);
+    }
 }
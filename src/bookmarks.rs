@@ -0,0 +1,230 @@
+use std::{fmt::Write, path::Path};
+
+use bevy::{
+    prelude::*,
+    render::camera::{ActiveCameras, Camera, OrthographicProjection},
+};
+use bevy_input_actionmap::InputMap;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+
+use crate::{
+    camera::{self, CameraTransition},
+    utils, EditorAction, EditorSettings,
+};
+
+pub(crate) const BOOKMARK_SLOTS: u8 = 9;
+
+/// A saved camera pose. `scale` is the scale of the [`OrthographicProjection`] of 2D cameras.
+#[derive(Clone, Copy)]
+struct Bookmark {
+    translation: Vec3,
+    rotation: Quat,
+    scale: Option<f32>,
+}
+
+#[derive(Default)]
+pub(crate) struct CameraBookmarks {
+    slots: [Option<Bookmark>; BOOKMARK_SLOTS as usize],
+    loaded: bool,
+}
+
+impl CameraBookmarks {
+    fn load(&mut self, path: &Path) {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                warn!("failed to read camera bookmarks from {}: {}", path.display(), e);
+                return;
+            }
+        };
+
+        for line in contents.lines() {
+            if !self.load_line(line) {
+                warn!("ignoring invalid camera bookmark `{}` in {}", line, path.display());
+            }
+        }
+    }
+
+    /// Stores the bookmark of a line of the bookmarks file, returning `false` if the line is invalid.
+    /// Blank lines are skipped.
+    fn load_line(&mut self, line: &str) -> bool {
+        if line.trim().is_empty() {
+            return true;
+        }
+        match parse_bookmark(line) {
+            Some((slot, bookmark)) if (1..=BOOKMARK_SLOTS).contains(&slot) => {
+                self.slots[slot as usize - 1] = Some(bookmark);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn save(&self, path: &Path) {
+        if let Err(e) = std::fs::write(path, self.file_contents()) {
+            warn!("failed to write camera bookmarks to {}: {}", path.display(), e);
+        }
+    }
+
+    fn file_contents(&self) -> String {
+        let mut contents = String::new();
+        for (i, bookmark) in self.slots.iter().enumerate() {
+            if let Some(Bookmark {
+                translation: t,
+                rotation: r,
+                scale,
+            }) = bookmark
+            {
+                let scale = scale.map_or_else(|| "-".to_string(), |scale| scale.to_string());
+                let _ = writeln!(
+                    contents,
+                    "{} {} {} {} {} {} {} {} {}",
+                    i + 1,
+                    t.x,
+                    t.y,
+                    t.z,
+                    r.x,
+                    r.y,
+                    r.z,
+                    r.w,
+                    scale
+                );
+            }
+        }
+        contents
+    }
+}
+
+/// Parses a line of the form `slot tx ty tz rx ry rz rw scale`, where `scale` is `-` for perspective cameras.
+fn parse_bookmark(line: &str) -> Option<(u8, Bookmark)> {
+    let mut parts = line.split_whitespace();
+    let slot = parts.next()?.parse().ok()?;
+    let mut numbers = [0.0; 7];
+    for number in numbers.iter_mut() {
+        *number = parts.next()?.parse().ok()?;
+    }
+    let scale = match parts.next()? {
+        "-" => None,
+        scale => Some(scale.parse().ok()?),
+    };
+
+    let [tx, ty, tz, rx, ry, rz, rw] = numbers;
+    let bookmark = Bookmark {
+        translation: Vec3::new(tx, ty, tz),
+        rotation: Quat::from_xyzw(rx, ry, rz, rw).normalize(),
+        scale,
+    };
+    Some((slot, bookmark))
+}
+
+pub(crate) fn camera_bookmark_system(
+    mut commands: Commands,
+    input: Res<InputMap<EditorAction>>,
+    editor_settings: Res<EditorSettings>,
+    egui_context: Res<EguiContext>,
+    active_cameras: Res<ActiveCameras>,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    cameras: Query<(&Camera, &Transform, Option<&OrthographicProjection>)>,
+) {
+    if !bookmarks.loaded {
+        bookmarks.loaded = true;
+        if let Some(path) = &editor_settings.camera_bookmarks_path {
+            bookmarks.load(path);
+        }
+    }
+
+    // the number keys are meant for the text field
+    if utils::ui_wants_keyboard_input(&egui_context, &editor_settings) {
+        return;
+    }
+
+    let camera_entity = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(camera, ..)| camera.window)
+    });
+    let (camera_entity, (_, transform, projection)) =
+        match camera_entity.and_then(|entity| Some((entity, cameras.get(entity).ok()?))) {
+            Some(camera) => camera,
+            None => return,
+        };
+    let scale = projection.map(|projection| projection.scale);
+
+    for slot in 1..=BOOKMARK_SLOTS {
+        if input.just_active(EditorAction::SaveCameraBookmark(slot)) {
+            bookmarks.slots[slot as usize - 1] = Some(Bookmark {
+                translation: transform.translation,
+                rotation: transform.rotation,
+                scale,
+            });
+            if let Some(path) = &editor_settings.camera_bookmarks_path {
+                bookmarks.save(path);
+            }
+            // ctrl + number also activates the binding without ctrl
+            continue;
+        }
+
+        if input.just_active(EditorAction::LoadCameraBookmark(slot)) {
+            if let Some(bookmark) = bookmarks.slots[slot as usize - 1] {
+                let target = Transform {
+                    translation: bookmark.translation,
+                    rotation: bookmark.rotation,
+                    ..*transform
+                };
+                let target_scale = scale.and(bookmark.scale);
+                commands
+                    .entity(camera_entity)
+                    .insert(CameraTransition::new(*transform, scale, target, target_scale));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmarks_round_trip() {
+        let mut bookmarks = CameraBookmarks::default();
+        bookmarks.slots[0] = Some(Bookmark {
+            translation: Vec3::new(1.5, -2.0, 30.25),
+            rotation: Quat::from_rotation_y(1.0),
+            scale: None,
+        });
+        bookmarks.slots[8] = Some(Bookmark {
+            translation: Vec3::new(0.0, 4.0, 999.9),
+            rotation: Quat::IDENTITY,
+            scale: Some(0.5),
+        });
+        let contents = bookmarks.file_contents();
+        assert!(contents.lines().next().unwrap().ends_with(" -"));
+
+        let mut loaded = CameraBookmarks::default();
+        for line in contents.lines() {
+            assert!(loaded.load_line(line), "invalid line `{}`", line);
+        }
+        for (saved, loaded) in bookmarks.slots.iter().zip(loaded.slots.iter()) {
+            match (saved, loaded) {
+                (Some(saved), Some(loaded)) => {
+                    assert!(saved.translation.abs_diff_eq(loaded.translation, 1e-6));
+                    assert!(saved.rotation.abs_diff_eq(loaded.rotation, 1e-6));
+                    assert_eq!(saved.scale, loaded.scale);
+                }
+                (None, None) => {}
+                _ => panic!("bookmark slots differ after loading"),
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_and_blank_lines() {
+        let mut bookmarks = CameraBookmarks::default();
+        assert!(bookmarks.load_line(""));
+        assert!(bookmarks.load_line("   "));
+        assert!(!bookmarks.load_line("10 0 0 0 0 0 0 1 -"));
+        assert!(!bookmarks.load_line("0 0 0 0 0 0 0 1 -"));
+        assert!(!bookmarks.load_line("1 0 0 0 0 0 0 1"));
+        assert!(!bookmarks.load_line("1 0 0 x 0 0 0 1 -"));
+        assert!(bookmarks.slots.iter().all(Option::is_none));
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::{
+    app::{Events, ManualEventReader},
+    asset::HandleId,
+    ecs::world::EntityRef,
+    prelude::*,
+    render::mesh::VertexAttributeValues,
+    utils::HashMap,
+};
+use bevy_mod_picking::PickableMesh;
+
+use crate::{debug_draw::DebugDrawMesh, icons::EditorIcon, plugin::EditorState, DebugDraw, EditorSettings};
+
+pub(crate) type BoundingBoxFilterFn = Box<dyn Fn(&EntityRef) -> bool + Send + Sync>;
+
+const SELECTED_COLOR: Color = Color::YELLOW;
+const PICKABLE_COLOR: Color = Color::GREEN;
+const FILTERED_COLOR: Color = Color::CYAN;
+
+/// Local bounds of every mesh which had its bounding box drawn, until the mesh gets modified.
+#[derive(Default)]
+pub(crate) struct MeshBounds {
+    bounds: HashMap<HandleId, (Vec3, Vec3)>,
+    mesh_events: ManualEventReader<AssetEvent<Mesh>>,
+}
+
+/// The minimum and maximum vertex position of `mesh`.
+pub(crate) fn mesh_aabb(mesh: &Mesh) -> Option<(Vec3, Vec3)> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float3(positions) => positions,
+        _ => return None,
+    };
+    let mut positions = positions.iter().map(|&position| Vec3::from(position));
+    let first = positions.next()?;
+    Some(positions.fold((first, first), |(min, max), position| (min.min(position), max.max(position))))
+}
+
+/// The axis-aligned box around the local bounds `min..max` after applying `transform`.
+pub(crate) fn world_aabb((min, max): (Vec3, Vec3), transform: &GlobalTransform) -> (Vec3, Vec3) {
+    let corners = (0..8).map(|i| {
+        let corner = Vec3::new(
+            if i & 1 == 0 { min.x } else { max.x },
+            if i & 2 == 0 { min.y } else { max.y },
+            if i & 4 == 0 { min.z } else { max.z },
+        );
+        transform.mul_vec3(corner)
+    });
+    corners.fold((Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)), |(min, max), corner| {
+        (min.min(corner), max.max(corner))
+    })
+}
+
+pub(crate) fn bounding_box_system(world: &mut World) {
+    let editor_settings = world.get_resource::<EditorSettings>().unwrap();
+    let show_selected = editor_settings.bounding_boxes_selected;
+    let show_pickable = editor_settings.bounding_boxes_pickable;
+    let show_filtered = editor_settings.bounding_boxes_filtered && editor_settings.bounding_box_filter.is_some();
+    if !show_selected && !show_pickable && !show_filtered {
+        return;
+    }
+
+    let mut query = world.query_filtered::<
+        (Entity, &Handle<Mesh>, &GlobalTransform, Option<&PickableMesh>),
+        (Without<DebugDrawMesh>, Without<EditorIcon>),
+    >();
+
+    world.resource_scope(|world, mut mesh_bounds: Mut<MeshBounds>| {
+        let mesh_bounds = &mut *mesh_bounds;
+        let mesh_events = world.get_resource::<Events<AssetEvent<Mesh>>>().unwrap();
+        for event in mesh_bounds.mesh_events.iter(mesh_events) {
+            if let AssetEvent::Modified { handle } | AssetEvent::Removed { handle } = event {
+                mesh_bounds.bounds.remove(&handle.id);
+            }
+        }
+
+        let editor_settings = world.get_resource::<EditorSettings>().unwrap();
+        let filter = editor_settings.bounding_box_filter.as_ref().filter(|_| show_filtered);
+        let selected = world.get_resource::<EditorState>().unwrap().currently_inspected;
+        let meshes = world.get_resource::<Assets<Mesh>>().unwrap();
+
+        let mut boxes = Vec::new();
+        for (entity, mesh, transform, pickable) in query.iter(world) {
+            let color = if show_selected && selected == Some(entity) {
+                SELECTED_COLOR
+            } else if show_pickable && pickable.is_some() {
+                PICKABLE_COLOR
+            } else if filter.map_or(false, |filter| filter(&world.entity(entity))) {
+                FILTERED_COLOR
+            } else {
+                continue;
+            };
+
+            let local_bounds = match mesh_bounds.bounds.get(&mesh.id) {
+                Some(local_bounds) => *local_bounds,
+                None => match meshes.get(mesh).and_then(mesh_aabb) {
+                    Some(local_bounds) => *mesh_bounds.bounds.entry(mesh.id).or_insert(local_bounds),
+                    None => continue,
+                },
+            };
+            boxes.push((world_aabb(local_bounds, transform), color));
+        }
+
+        let mut debug_draw = world.get_resource_mut::<DebugDraw>().unwrap();
+        for ((min, max), color) in boxes {
+            debug_draw.aabb(min, max, color, 0.0);
+        }
+    });
+}
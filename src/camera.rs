@@ -0,0 +1,456 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    render::{
+        camera::{ActiveCameras, Camera, CameraProjection, OrthographicProjection},
+        render_graph::base::camera,
+    },
+    ui::camera::CAMERA_UI,
+    window::{WindowFocused, WindowId},
+};
+use bevy_fly_camera::FlyCamera;
+use bevy_input_actionmap::InputMap;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+use bevy_mod_picking::{PickingCamera, PickingCameraBundle};
+use bevy_orbit_controls::OrbitCamera;
+
+use crate::{look_through::LookThrough, plugin::EditorState, EditorAction, EditorSettings, FlyCameraSettings};
+
+/// Marker for cameras which belong to the editor instead of the game.
+///
+/// The editor spawns one when switching to the [dedicated editor camera](EditorSettings::editor_camera),
+/// and the camera of the second window in [`EditorPluginSecondWindow`](crate::EditorPluginSecondWindow) has it as well.
+pub(crate) struct EditorCamera;
+
+/// Marker for game cameras which should get picking, fly camera and pan camera components through the `auto_*` settings
+/// of [`EditorSettings`], in addition to the cameras selected by [`EditorSettings::filter_cameras`].
+pub struct EditorTarget;
+
+/// Marker for fly cameras which were added by the editor and follow [`EditorSettings::fly_camera_settings`].
+pub(crate) struct ManagedFlyCamera;
+
+/// Marker for picking cameras which were added by the editor, the only ones it suspends outside of the viewport.
+pub(crate) struct ManagedPickingCamera;
+
+/// The range the fly camera speed can be adjusted in with the mouse wheel.
+pub(crate) const FLY_CAMERA_SPEED_RANGE: std::ops::RangeInclusive<f32> = 0.05..=10.0;
+
+pub(crate) fn fly_camera(settings: &FlyCameraSettings, enabled: bool) -> FlyCamera {
+    let mut fly_camera = FlyCamera {
+        enabled,
+        ..Default::default()
+    };
+    apply_fly_camera_settings(&mut fly_camera, settings, false);
+    fly_camera
+}
+
+fn apply_fly_camera_settings(fly_camera: &mut FlyCamera, settings: &FlyCameraSettings, mouse_look: bool) {
+    let [forward, backward, left, right, up, down] = settings.key_layout.keys();
+    fly_camera.max_speed = settings.max_speed;
+    fly_camera.accel = settings.accel;
+    fly_camera.sensitivity = settings.sensitivity;
+    // while mouse-looking the camera rotates without holding a button
+    fly_camera.only_if_mouse_down = if mouse_look { None } else { settings.mouse_button };
+    fly_camera.key_forward = forward;
+    fly_camera.key_backward = backward;
+    fly_camera.key_left = left;
+    fly_camera.key_right = right;
+    fly_camera.key_up = up;
+    fly_camera.key_down = down;
+}
+
+/// Adjusts the fly camera speed with the mouse wheel and applies the [`FlyCameraSettings`] and [`MouseLook`] state
+/// to all managed fly cameras whenever either of them changes.
+pub(crate) fn fly_camera_settings_system(
+    egui_context: Res<EguiContext>,
+    mut editor_settings: ResMut<EditorSettings>,
+    mouse_look: Res<MouseLook>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut applied: Local<Option<(FlyCameraSettings, bool)>>,
+    mut fly_cameras: Query<(&mut FlyCamera, ChangeTrackers<ManagedFlyCamera>)>,
+) {
+    let scroll: f32 = mouse_wheel
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / 16.0,
+        })
+        .sum();
+    let pointer_over_ui = egui_context
+        .try_ctx_for_window(editor_settings.window)
+        .map_or(false, |ctx| ctx.wants_pointer_input());
+    let settings = &editor_settings.fly_camera_settings;
+    if scroll != 0.0 && editor_settings.fly_camera && settings.scroll_adjusts_speed && !pointer_over_ui {
+        let max_speed =
+            (settings.max_speed * 1.1f32.powf(scroll)).clamp(*FLY_CAMERA_SPEED_RANGE.start(), *FLY_CAMERA_SPEED_RANGE.end());
+        editor_settings.fly_camera_settings.max_speed = max_speed;
+    }
+
+    let state = (editor_settings.fly_camera_settings, mouse_look.grabbed);
+    let changed = applied.as_ref() != Some(&state);
+    for (mut fly_camera, managed) in fly_cameras.iter_mut() {
+        if changed || managed.is_added() {
+            apply_fly_camera_settings(&mut fly_camera, &state.0, state.1);
+        }
+    }
+    *applied = Some(state);
+}
+
+/// Mouse-look mode of the fly camera, where the cursor is locked and hidden and every mouse movement rotates the camera.
+/// Active while the right mouse button is held or after [`EditorAction::ToggleMouseLook`].
+#[derive(Default)]
+pub(crate) struct MouseLook {
+    toggled: bool,
+    holding: bool,
+    grabbed: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn mouse_look_system(
+    input: Res<InputMap<EditorAction>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keys: Res<Input<KeyCode>>,
+    mut focus_events: EventReader<WindowFocused>,
+    egui_context: Res<EguiContext>,
+    editor_settings: Res<EditorSettings>,
+    mut mouse_look: ResMut<MouseLook>,
+    mut windows: ResMut<Windows>,
+) {
+    let (wants_pointer, wants_keyboard) = egui_context
+        .try_ctx_for_window(editor_settings.window)
+        .map_or((false, false), |ctx| (ctx.wants_pointer_input(), ctx.wants_keyboard_input()));
+
+    if input.just_active(EditorAction::ToggleMouseLook) {
+        mouse_look.toggled = !mouse_look.toggled;
+    }
+    if mouse_buttons.just_pressed(MouseButton::Right) && !wants_pointer {
+        mouse_look.holding = true;
+    }
+    if !mouse_buttons.pressed(MouseButton::Right) {
+        mouse_look.holding = false;
+    }
+
+    // give the cursor back as soon as the editor ui needs it
+    let lost_focus = focus_events
+        .iter()
+        .any(|event| event.id == editor_settings.window && !event.focused);
+    if lost_focus || wants_keyboard || keys.just_pressed(KeyCode::Escape) || !editor_settings.fly_camera {
+        mouse_look.toggled = false;
+        mouse_look.holding = false;
+    }
+
+    let active = mouse_look.toggled || mouse_look.holding;
+    if active != mouse_look.grabbed {
+        if let Some(window) = windows.get_mut(editor_settings.window) {
+            crate::utils::toggle_grab_cursor(window);
+        }
+        mouse_look.grabbed = active;
+    }
+}
+
+/// Sets yaw and pitch of the fly camera so that it keeps looking in the direction of `rotation` once it gets moved.
+pub(crate) fn sync_fly_camera(fly_camera: &mut FlyCamera, rotation: Quat) {
+    let forward = rotation * -Vec3::Z;
+    fly_camera.pitch = (-forward.y).clamp(-1.0, 1.0).asin().to_degrees();
+    fly_camera.yaw = (-forward.x).atan2(-forward.z).to_degrees();
+}
+
+/// Moves the orbit center in front of the camera at the current orbit distance,
+/// so that the orbit camera keeps the pose of `transform` instead of jumping back.
+pub(crate) fn sync_orbit_camera(orbit_camera: &mut OrbitCamera, transform: &Transform) {
+    let forward = transform.rotation * -Vec3::Z;
+    orbit_camera.center = transform.translation + forward * orbit_camera.distance;
+
+    // inverse of the rotation in `bevy_orbit_controls`, which places the camera at `rot(x, y) * Y` around the center
+    let offset = -forward;
+    orbit_camera.y = offset.y.clamp(-1.0, 1.0).acos();
+    orbit_camera.x = (-offset.x).atan2(-offset.z);
+}
+
+/// The camera through which the editor window currently looks at the world.
+/// 3D cameras are preferred over 2D ones, UI cameras are never considered.
+pub(crate) fn viewport_camera(
+    editor_settings: &EditorSettings,
+    active_cameras: &ActiveCameras,
+    mut camera_window: impl FnMut(Entity) -> Option<WindowId>,
+) -> Option<Entity> {
+    let mut candidates: Vec<(&str, Entity)> = active_cameras
+        .iter()
+        .filter(|active_camera| active_camera.name != CAMERA_UI)
+        .filter_map(|active_camera| Some((active_camera.name.as_str(), active_camera.entity?)))
+        .filter(|(_, entity)| camera_window(*entity) == Some(editor_settings.window))
+        .collect();
+    candidates.sort_by_key(|(name, _)| *name == camera::CAMERA_2D);
+    candidates.first().map(|(_, entity)| *entity)
+}
+
+/// Duration of the camera animations in seconds.
+const TRANSITION_DURATION: f32 = 0.4;
+
+/// Eased progress of an animation which started `elapsed` seconds ago, reaching `1.0` once it is done.
+fn transition_progress(elapsed: f32) -> f32 {
+    let t = (elapsed / TRANSITION_DURATION).min(1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Smoothly moves a camera to a new pose. Fly and orbit cameras are updated to match once the transition is done.
+pub(crate) struct CameraTransition {
+    from: Transform,
+    to: Transform,
+    from_scale: Option<f32>,
+    to_scale: Option<f32>,
+    elapsed: f32,
+}
+
+impl CameraTransition {
+    /// `scale` is the scale of the [`OrthographicProjection`], if the camera has one.
+    pub fn new(from: Transform, from_scale: Option<f32>, to: Transform, to_scale: Option<f32>) -> Self {
+        CameraTransition {
+            from,
+            to,
+            from_scale,
+            to_scale,
+            elapsed: 0.0,
+        }
+    }
+}
+
+pub(crate) fn camera_transition_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &mut CameraTransition,
+        &mut Transform,
+        &mut Camera,
+        Option<&mut OrthographicProjection>,
+        Option<&mut FlyCamera>,
+        Option<&mut OrbitCamera>,
+    )>,
+) {
+    for (entity, mut transition, mut transform, mut camera, projection, fly_camera, orbit_camera) in query.iter_mut() {
+        transition.elapsed += time.delta_seconds();
+        let t = transition_progress(transition.elapsed);
+
+        transform.translation = transition.from.translation.lerp(transition.to.translation, t);
+        transform.rotation = transition.from.rotation.slerp(transition.to.rotation, t);
+
+        if let (Some(mut projection), Some(from), Some(to)) = (projection, transition.from_scale, transition.to_scale) {
+            projection.scale = from + (to - from) * t;
+            camera.projection_matrix = projection.get_projection_matrix();
+        }
+
+        if let Some(mut fly_camera) = fly_camera {
+            sync_fly_camera(&mut fly_camera, transform.rotation);
+        }
+
+        if t >= 1.0 {
+            if let Some(mut orbit_camera) = orbit_camera {
+                sync_orbit_camera(&mut orbit_camera, &transform);
+            }
+            commands.entity(entity).remove::<CameraTransition>();
+        }
+    }
+}
+
+/// Smoothly moves the center of an [`OrbitCamera`] to a new target instead of snapping to it.
+pub(crate) struct OrbitCenterTransition {
+    from: Vec3,
+    to: Vec3,
+    elapsed: f32,
+}
+
+impl OrbitCenterTransition {
+    pub fn new(from: Vec3, to: Vec3) -> Self {
+        OrbitCenterTransition { from, to, elapsed: 0.0 }
+    }
+}
+
+pub(crate) fn orbit_center_transition_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut OrbitCenterTransition, &mut OrbitCamera)>,
+) {
+    for (entity, mut transition, mut orbit_camera) in query.iter_mut() {
+        transition.elapsed += time.delta_seconds();
+        let t = transition_progress(transition.elapsed);
+        orbit_camera.center = transition.from.lerp(transition.to, t);
+
+        if t >= 1.0 {
+            commands.entity(entity).remove::<OrbitCenterTransition>();
+        }
+    }
+}
+
+/// The entity followed with [`EditorSettings::follow_selected`] and its position in the last frame.
+#[derive(Default)]
+pub(crate) struct FollowSelected {
+    target: Option<(Entity, Vec3)>,
+}
+
+/// Moves the viewport camera along with the inspected entity, keeping the offset between them.
+/// The center of an [`OrbitCamera`] is moved as well, so it can still be used to orbit around the entity.
+pub(crate) fn follow_selected_system(
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    active_cameras: Res<ActiveCameras>,
+    mut follow: ResMut<FollowSelected>,
+    targets: Query<&GlobalTransform>,
+    mut cameras: Query<(&Camera, &mut Transform, Option<&mut OrbitCamera>)>,
+) {
+    let target = editor_state
+        .currently_inspected
+        .filter(|_| editor_settings.follow_selected)
+        .and_then(|entity| Some((entity, targets.get(entity).ok()?.translation)));
+    let delta = match (follow.target, target) {
+        (Some((previous, last_position)), Some((entity, position))) if previous == entity => position - last_position,
+        _ => Vec3::ZERO,
+    };
+    follow.target = target;
+    if delta == Vec3::ZERO {
+        return;
+    }
+
+    let camera_entity = viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get_mut(entity).ok().map(|(camera, ..)| camera.window)
+    });
+    let camera_entity = match camera_entity {
+        // following the camera itself would move it forever
+        Some(camera_entity) if target.map(|(entity, _)| entity) != Some(camera_entity) => camera_entity,
+        _ => return,
+    };
+
+    if let Ok((_, mut transform, orbit_camera)) = cameras.get_mut(camera_entity) {
+        transform.translation += delta;
+        if let Some(mut orbit_camera) = orbit_camera {
+            orbit_camera.center += delta;
+        }
+    }
+}
+
+/// Marker for cameras whose [`PickingCamera`] was removed because they don't render the editor viewport.
+pub(crate) struct SuspendedPickingCamera;
+
+/// While the dedicated editor camera or look-through is in use, only lets the camera in the editor viewport pick meshes.
+/// Every [`PickingCamera`] casts a ray through the cursor, so cameras which aren't visible would select things as well.
+/// Only picking cameras added by the editor are suspended, and all of them are restored once neither is in use.
+/// The editor camera itself never picks while it isn't in the viewport.
+pub(crate) fn viewport_picking_system(
+    mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
+    look_through: Res<LookThrough>,
+    active_cameras: Res<ActiveCameras>,
+    cameras: Query<(
+        Entity,
+        &Camera,
+        Option<&PickingCamera>,
+        Option<&SuspendedPickingCamera>,
+        Option<&EditorCamera>,
+    )>,
+    managed: Query<(), With<ManagedPickingCamera>>,
+) {
+    let viewport_camera = viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(_, camera, ..)| camera.window)
+    });
+    let viewport_only = editor_settings.editor_camera || look_through.is_active();
+
+    for (entity, camera, picking_camera, suspended, editor_camera) in cameras.iter() {
+        if managed.get(entity).is_err() {
+            continue;
+        }
+        let suspend = (viewport_only || editor_camera.is_some())
+            && camera.window == editor_settings.window
+            && viewport_camera != Some(entity);
+        if picking_camera.is_some() && suspend {
+            commands
+                .entity(entity)
+                .remove::<PickingCamera>()
+                .insert(SuspendedPickingCamera);
+        } else if suspended.is_some() && !suspend {
+            commands
+                .entity(entity)
+                .remove::<SuspendedPickingCamera>()
+                .insert_bundle(PickingCameraBundle::default());
+        }
+    }
+}
+
+/// Whether the fly camera of `entity` should react to input.
+/// While the dedicated editor camera is in use, the game camera is left alone and vice versa.
+pub(crate) fn fly_camera_enabled(editor_settings: &EditorSettings, editor_state: &EditorState, entity: Entity) -> bool {
+    let is_editor_camera = editor_state.editor_camera == Some(entity);
+    editor_settings.fly_camera && is_editor_camera == editor_settings.editor_camera
+}
+
+pub(crate) fn editor_camera_system(
+    mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
+    mut editor_state: ResMut<EditorState>,
+    mut active_cameras: ResMut<ActiveCameras>,
+    transforms: Query<&GlobalTransform, With<Camera>>,
+    look_through: Res<LookThrough>,
+    mut fly_cameras: Query<(Entity, &mut FlyCamera)>,
+) {
+    // the second window always renders through its own camera
+    if editor_settings.window != WindowId::primary() {
+        return;
+    }
+    // the viewport shows the selected camera until returning to the editor camera
+    if look_through.is_active() {
+        return;
+    }
+
+    let active_camera = match active_cameras.get_mut(camera::CAMERA_3D) {
+        Some(active_camera) => active_camera,
+        None => return,
+    };
+
+    let editor_camera = editor_state.editor_camera.filter(|&entity| transforms.get(entity).is_ok());
+    let using_editor_camera = editor_camera.is_some() && active_camera.entity == editor_camera;
+    if editor_settings.editor_camera == using_editor_camera {
+        return;
+    }
+
+    if editor_settings.editor_camera {
+        let game_camera = match active_camera.entity {
+            Some(game_camera) => game_camera,
+            None => return,
+        };
+
+        let editor_camera = editor_camera.unwrap_or_else(|| {
+            let transform = transforms
+                .get(game_camera)
+                .map_or_else(|_| Transform::default(), |transform| Transform::from(*transform));
+            let mut fly_camera = fly_camera(&editor_settings.fly_camera_settings, editor_settings.fly_camera);
+            sync_fly_camera(&mut fly_camera, transform.rotation);
+
+            commands
+                .spawn_bundle(PerspectiveCameraBundle {
+                    camera: Camera::default(),
+                    transform,
+                    ..Default::default()
+                })
+                .insert(Name::new("Editor Camera"))
+                .insert(EditorCamera)
+                .insert(fly_camera)
+                .insert(ManagedFlyCamera)
+                .insert_bundle(PickingCameraBundle::default())
+                .insert(ManagedPickingCamera)
+                .id()
+        });
+
+        editor_state.editor_camera = Some(editor_camera);
+        editor_state.game_camera = Some(game_camera);
+        active_camera.entity = Some(editor_camera);
+    } else {
+        // `None` lets bevy pick the camera named `CAMERA_3D` again
+        active_camera.entity = editor_state
+            .game_camera
+            .take()
+            .filter(|&entity| transforms.get(entity).is_ok());
+    }
+
+    for (entity, mut fly_camera) in fly_cameras.iter_mut() {
+        fly_camera.enabled = fly_camera_enabled(&editor_settings, &editor_state, entity);
+    }
+}
@@ -0,0 +1,15 @@
+#version 450
+
+layout(location = 0) in vec3 Vertex_Position;
+layout(location = 1) in vec4 Vertex_Color;
+
+layout(location = 0) out vec4 v_Color;
+
+layout(set = 0, binding = 0) uniform CameraViewProj {
+    mat4 ViewProj;
+};
+
+void main() {
+    v_Color = Vertex_Color;
+    gl_Position = ViewProj * vec4(Vertex_Position, 1.0);
+}
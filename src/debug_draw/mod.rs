@@ -0,0 +1,256 @@
+use bevy::{
+    prelude::*,
+    reflect::TypeUuid,
+    render::{
+        camera::{Camera, RenderLayers},
+        pipeline::{
+            CullMode, FrontFace, PipelineDescriptor, PolygonMode, PrimitiveState, PrimitiveTopology, RenderPipeline,
+        },
+        shader::{ShaderStage, ShaderStages},
+    },
+    ui::camera::CAMERA_UI,
+};
+
+use crate::EditorSettings;
+
+pub(crate) const DEBUG_LINES_PIPELINE_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(PipelineDescriptor::TYPE_UUID, 0x5c1e_2b0d_83f4_a761);
+
+const CIRCLE_SEGMENTS: usize = 32;
+/// The render layer of the debug lines, which only the cameras of the editor window see in addition to their own layers.
+const DEBUG_LAYER: u8 = (RenderLayers::TOTAL_LAYERS - 1) as u8;
+
+/// Immediate-mode drawing of lines and simple shapes for debugging, available to editor code and game systems alike.
+///
+/// Every shape is drawn for `duration` seconds, or for a single frame if the duration is `0.0`.
+/// Lines are depth tested against the scene and only rendered by the cameras of the editor window,
+/// so with [`EditorPluginSecondWindow`](crate::EditorPluginSecondWindow) they don't show up in the game window.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_editor_pls::DebugDraw;
+/// fn draw_forward(mut debug_draw: ResMut<DebugDraw>, query: Query<&GlobalTransform, With<Camera>>) {
+///     for transform in query.iter() {
+///         let forward = transform.rotation * -Vec3::Z;
+///         debug_draw.arrow(transform.translation, transform.translation + forward, Color::RED, 0.0);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct DebugDraw {
+    lines: Vec<DebugLine>,
+}
+
+struct DebugLine {
+    start: Vec3,
+    end: Vec3,
+    color: Color,
+    remaining: f32,
+}
+
+impl DebugDraw {
+    pub fn line(&mut self, start: Vec3, end: Vec3, color: Color, duration: f32) {
+        self.lines.push(DebugLine {
+            start,
+            end,
+            color,
+            remaining: duration,
+        });
+    }
+
+    /// Draws a line from `start` to `end` with an arrow head at `end`.
+    pub fn arrow(&mut self, start: Vec3, end: Vec3, color: Color, duration: f32) {
+        self.line(start, end, color, duration);
+
+        let direction = end - start;
+        let length = direction.length();
+        if length <= f32::EPSILON {
+            return;
+        }
+        let direction = direction / length;
+        let (u, v) = orthonormal_basis(direction);
+        let head_length = length * 0.2;
+        let base = end - direction * head_length;
+        for side in [u, -u, v, -v].iter() {
+            self.line(end, base + *side * head_length * 0.4, color, duration);
+        }
+    }
+
+    /// Draws the edges of a unit cube which is moved, rotated and scaled by `transform`.
+    pub fn cuboid(&mut self, transform: Transform, color: Color, duration: f32) {
+        let corners: Vec<Vec3> = (0..8)
+            .map(|i| {
+                let corner = Vec3::new(
+                    if i & 1 == 0 { -0.5 } else { 0.5 },
+                    if i & 2 == 0 { -0.5 } else { 0.5 },
+                    if i & 4 == 0 { -0.5 } else { 0.5 },
+                );
+                transform.mul_vec3(corner)
+            })
+            .collect();
+
+        // corners which differ in exactly one axis share an edge
+        for i in 0..8 {
+            for axis in [1, 2, 4].iter() {
+                if i & axis == 0 {
+                    self.line(corners[i], corners[i | axis], color, duration);
+                }
+            }
+        }
+    }
+
+    /// Draws an axis-aligned box between the corners `min` and `max`.
+    pub fn aabb(&mut self, min: Vec3, max: Vec3, color: Color, duration: f32) {
+        let transform = Transform {
+            translation: (min + max) / 2.0,
+            scale: max - min,
+            ..Default::default()
+        };
+        self.cuboid(transform, color, duration);
+    }
+
+    /// Draws a circle around `center` in the plane perpendicular to `normal`.
+    pub fn circle(&mut self, center: Vec3, normal: Vec3, radius: f32, color: Color, duration: f32) {
+        let (u, v) = orthonormal_basis(normal.normalize());
+        let point = |i: usize| {
+            let angle = i as f32 / CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU;
+            center + (u * angle.cos() + v * angle.sin()) * radius
+        };
+        for i in 0..CIRCLE_SEGMENTS {
+            self.line(point(i), point(i + 1), color, duration);
+        }
+    }
+
+    /// Draws a sphere as three circles around the coordinate axes.
+    pub fn sphere(&mut self, center: Vec3, radius: f32, color: Color, duration: f32) {
+        for axis in [Vec3::X, Vec3::Y, Vec3::Z].iter() {
+            self.circle(center, *axis, radius, color, duration);
+        }
+    }
+
+    /// Removes all shapes, including the ones which haven't reached their duration yet.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+/// Two vectors which are perpendicular to `normal` and to each other.
+fn orthonormal_basis(normal: Vec3) -> (Vec3, Vec3) {
+    let helper = if normal.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+    let u = normal.cross(helper).normalize();
+    let v = normal.cross(u);
+    (u, v)
+}
+
+/// Marker for the mesh the [`DebugDraw`] lines are rendered with.
+pub(crate) struct DebugDrawMesh;
+
+pub(crate) fn build_debug_lines_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor {
+    PipelineDescriptor {
+        name: Some("debug_lines".into()),
+        primitive: PrimitiveState {
+            topology: PrimitiveTopology::LineList,
+            strip_index_format: None,
+            front_face: FrontFace::Ccw,
+            cull_mode: CullMode::None,
+            polygon_mode: PolygonMode::Fill,
+        },
+        ..PipelineDescriptor::default_config(ShaderStages {
+            vertex: shaders.add(Shader::from_glsl(ShaderStage::Vertex, include_str!("debug_lines.vert"))),
+            fragment: Some(shaders.add(Shader::from_glsl(ShaderStage::Fragment, include_str!("debug_lines.frag")))),
+        })
+    }
+}
+
+/// The mesh always contains at least one line, because meshes without vertices can't be drawn.
+fn set_lines(mesh: &mut Mesh, positions: Vec<[f32; 3]>, colors: Vec<[f32; 4]>) {
+    let (positions, colors) = if positions.is_empty() {
+        (vec![[0.0; 3]; 2], vec![[0.0; 4]; 2])
+    } else {
+        (positions, colors)
+    };
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+}
+
+pub(crate) fn setup_debug_draw(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+    set_lines(&mut mesh, Vec::new(), Vec::new());
+
+    commands
+        .spawn_bundle(MeshBundle {
+            mesh: meshes.add(mesh),
+            render_pipelines: RenderPipelines::from_pipelines(vec![RenderPipeline::new(
+                DEBUG_LINES_PIPELINE_HANDLE.typed(),
+            )]),
+            visible: Visible {
+                is_visible: false,
+                is_transparent: true,
+            },
+            ..Default::default()
+        })
+        .insert(Name::new("Debug Draw"))
+        .insert(RenderLayers::layer(DEBUG_LAYER))
+        .insert(DebugDrawMesh);
+}
+
+/// Adds the layer of the debug lines to every camera rendering to the editor window.
+pub(crate) fn debug_draw_layer_system(
+    mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
+    mut cameras: Query<(Entity, &Camera, Option<&mut RenderLayers>)>,
+) {
+    let debug_layer = RenderLayers::layer(DEBUG_LAYER);
+    for (entity, camera, render_layers) in cameras.iter_mut() {
+        if camera.window != editor_settings.window || camera.name.as_deref() == Some(CAMERA_UI) {
+            continue;
+        }
+        match render_layers {
+            Some(mut render_layers) => {
+                if !render_layers.intersects(&debug_layer) {
+                    *render_layers = render_layers.with(DEBUG_LAYER);
+                }
+            }
+            // cameras without render layers see layer 0
+            None => {
+                commands.entity(entity).insert(RenderLayers::layer(0).with(DEBUG_LAYER));
+            }
+        }
+    }
+}
+
+pub(crate) fn debug_draw_system(
+    time: Res<Time>,
+    mut debug_draw: ResMut<DebugDraw>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(&Handle<Mesh>, &mut Visible), With<DebugDrawMesh>>,
+) {
+    for (handle, mut visible) in query.iter_mut() {
+        if debug_draw.lines.is_empty() && !visible.is_visible {
+            continue;
+        }
+        let mesh = match meshes.get_mut(handle) {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+
+        let positions: Vec<[f32; 3]> = debug_draw
+            .lines
+            .iter()
+            .flat_map(|line| std::array::IntoIter::new([line.start.into(), line.end.into()]))
+            .collect();
+        let colors: Vec<[f32; 4]> = debug_draw
+            .lines
+            .iter()
+            .flat_map(|line| std::iter::repeat(line.color.as_linear_rgba_f32()).take(2))
+            .collect();
+        set_lines(mesh, positions, colors);
+        visible.is_visible = !debug_draw.lines.is_empty();
+    }
+
+    let delta = time.delta_seconds();
+    for line in debug_draw.lines.iter_mut() {
+        line.remaining -= delta;
+    }
+    debug_draw.lines.retain(|line| line.remaining > 0.0);
+}
@@ -1,18 +1,38 @@
-use std::{any::Any, path::Path};
+use std::{
+    any::{Any, TypeId},
+    path::{Path, PathBuf},
+};
 
-use bevy::{app::Events, ecs::world::WorldCell, utils::StableHashMap, window::WindowId};
+use bevy::{
+    app::Events,
+    ecs::world::{EntityRef, WorldCell},
+    render::camera::Camera,
+    utils::{HashMap, StableHashMap},
+    window::WindowId,
+};
 use bevy::{ecs::component::Component, prelude::*};
 
 use bevy_inspector_egui::egui;
 
+use crate::{bounds::BoundingBoxFilterFn, labels::LabelFilterFn};
+
 type UiFn = Box<dyn Fn(&mut egui::Ui, &mut dyn Any, &WorldCell) + Send + Sync>;
 type DragAndDropHandler = Box<dyn Fn(&Path, &mut World) + Send + Sync>;
+pub(crate) type RestoreResourceFn = Box<dyn FnOnce(&mut World) + Send + Sync>;
+type SnapshotResourceFn = Box<dyn Fn(&World) -> Option<RestoreResourceFn> + Send + Sync>;
+pub(crate) type RemoveComponentFn = fn(&mut World, Entity);
+type CameraFilterFn = Box<dyn Fn(&Camera) -> bool + Send + Sync>;
 
 /// Configuration for for editor
 pub struct EditorSettings {
     pub(crate) menu_items:
         StableHashMap<&'static str, Vec<(Option<&'static str>, Box<dyn Any + Send + Sync + 'static>, UiFn)>>,
     pub(crate) drag_and_drop_handlers: Vec<(&'static [&'static str], DragAndDropHandler)>,
+    pub(crate) snapshot_resources: Vec<SnapshotResourceFn>,
+    pub(crate) removable_components: HashMap<TypeId, RemoveComponentFn>,
+    pub(crate) camera_filter: Option<CameraFilterFn>,
+    pub(crate) bounding_box_filter: Option<BoundingBoxFilterFn>,
+    pub(crate) label_filter: Option<LabelFilterFn>,
 
     /// Whether clicking meshes with a [PickableBundle](bevy_mod_picking::PickableBundle) opens the inspector.
     /// Can be toggled in the editor UI.
@@ -20,10 +40,67 @@ pub struct EditorSettings {
     /// Whether wireframe should be shown.
     /// Can be toggled in the editor UI.
     pub show_wireframes: bool,
+    /// Whether the bounding box of the inspected entity should be shown.
+    /// Can be toggled in the editor UI.
+    pub bounding_boxes_selected: bool,
+    /// Whether the bounding boxes of all meshes with a [PickableBundle](bevy_mod_picking::PickableBundle) should be shown.
+    /// Can be toggled in the editor UI.
+    pub bounding_boxes_pickable: bool,
+    /// Whether the bounding boxes of the entities matching the [bounding box filter](EditorSettings::filter_bounding_boxes) should be shown.
+    /// Can be toggled in the editor UI.
+    pub bounding_boxes_filtered: bool,
+    /// Whether the vertex normals and tangents of the inspected mesh should be shown.
+    /// Can be toggled in the editor UI.
+    pub normals_selected: bool,
+    /// Whether the vertex normals and tangents of all meshes should be shown.
+    /// Can be toggled in the editor UI.
+    pub normals_all: bool,
+    /// Length of the lines drawn for normals and tangents. Can be changed in the editor UI.
+    pub normals_length: f32,
+    /// Whether the local axes of the inspected entity and its descendants should be shown,
+    /// with lines connecting each parent to its children.
+    /// Can be toggled in the editor UI.
+    pub hierarchy_axes: bool,
+    /// Whether a reference grid should be drawn around the camera.
+    /// Can be toggled in the editor UI.
+    pub show_grid: bool,
+    /// Whether arrows along the X, Y and Z axis should be drawn at the world origin.
+    /// Can be toggled in the editor UI.
+    pub show_world_axes: bool,
+    /// Whether lights should be drawn as icons with their direction and range, which can be clicked to inspect them.
+    /// Lights can be clicked to inspect them regardless of this setting.
+    /// Can be toggled in the editor UI.
+    pub show_lights: bool,
+    /// Whether the view frustums of all cameras other than the one in the viewport should be drawn.
+    /// While they are shown, cameras can be clicked to inspect them.
+    /// Can be toggled in the editor UI.
+    pub show_camera_frustums: bool,
+    /// Whether the names of entities should be shown next to them in the viewport.
+    /// Clicking a name inspects the entity. Can be toggled in the editor UI.
+    pub show_labels: bool,
+    /// Entities further away from the camera than this don't get a name label.
+    pub label_max_distance: f32,
+    /// Spacing and orientation of the reference grid.
+    pub grid_settings: GridSettings,
     /// Whether the camera can be controlled with WASD.
     /// Can be toggled in the editor UI.
     pub fly_camera: bool,
     pub orbit_camera: bool,
+    /// Speed, sensitivity and controls of the fly cameras added by the editor.
+    /// Can be changed in the **Camera settings** window.
+    pub fly_camera_settings: FlyCameraSettings,
+    /// Whether snapping to a [view preset](crate::ViewPreset) also switches the camera to an orthographic projection.
+    /// [`EditorAction::ToggleOrthographic`](crate::EditorAction::ToggleOrthographic) switches back to perspective.
+    pub view_presets_orthographic: bool,
+    /// Whether the viewport is rendered through a dedicated editor camera instead of the game camera.
+    /// The game camera keeps its transform and components while the editor camera is in use,
+    /// except that only the camera in the viewport is used for picking.
+    /// Only applies when the editor is displayed in the primary window.
+    /// Can be toggled in the editor UI.
+    pub editor_camera: bool,
+    /// Whether the camera moves along with the inspected entity, keeping its offset to it.
+    /// Can be toggled in the editor UI.
+    pub follow_selected: bool,
 
     /// If enabled, [`PickableBundle`](bevy_mod_picking::PickableBundle) will be added to all meshes
     pub auto_pickable: bool,
@@ -33,13 +110,25 @@ pub struct EditorSettings {
     pub auto_flycam: bool,
     /// If enabled, [`PanCam`](bevy_pancam::Pancam) will automatically be added to your 2d cameras
     pub auto_pancam: bool,
+    /// Mouse buttons, zoom speed and limits of the pan cameras added by the editor.
+    pub pan_cam_settings: PanCamSettings,
 
     /// Shows a panel displaying the current FPS. Only available if the [`FrameTimeDiagnosticsPlugin`](bevy::diagnostic::FrameTimeDiagnosticsPlugin) is active.
     pub performance_panel: bool,
+    /// Shows a window for changing the [`fly_camera_settings`](EditorSettings::fly_camera_settings).
+    pub camera_settings_panel: bool,
+    /// Shows a bar at the bottom of the window with the world position and entity under the mouse cursor.
+    pub status_bar: bool,
+    /// Shows a window listing all lights and the [`AmbientLight`](bevy::pbr::AmbientLight).
+    pub lights_panel: bool,
 
     /// Controls whether the editor panel is shown.
     pub display_ui: bool,
 
+    /// File the camera bookmarks (see [`EditorAction::SaveCameraBookmark`](crate::EditorAction::SaveCameraBookmark)) are persisted to.
+    /// If `None`, which is the default, bookmarks are only kept until the app exits.
+    pub camera_bookmarks_path: Option<PathBuf>,
+
     /// The window to display the editor in.
     pub window: WindowId,
 }
@@ -48,20 +137,159 @@ impl Default for EditorSettings {
         EditorSettings {
             menu_items: StableHashMap::default(),
             drag_and_drop_handlers: Vec::new(),
+            snapshot_resources: Vec::new(),
+            removable_components: HashMap::default(),
+            camera_filter: None,
+            bounding_box_filter: None,
+            label_filter: None,
             click_to_inspect: true,
             show_wireframes: false,
+            bounding_boxes_selected: false,
+            bounding_boxes_pickable: false,
+            bounding_boxes_filtered: false,
+            normals_selected: false,
+            normals_all: false,
+            normals_length: 0.1,
+            hierarchy_axes: false,
+            show_grid: false,
+            show_world_axes: false,
+            grid_settings: GridSettings::default(),
+            show_lights: false,
+            show_camera_frustums: false,
+            show_labels: false,
+            label_max_distance: 50.0,
             fly_camera: false,
             orbit_camera: false,
+            fly_camera_settings: FlyCameraSettings::default(),
+            view_presets_orthographic: false,
+            editor_camera: false,
+            follow_selected: false,
             auto_pickable: false,
             auto_pickable_camera: false,
             auto_flycam: false,
             auto_pancam: false,
+            pan_cam_settings: PanCamSettings::default(),
             performance_panel: false,
+            camera_settings_panel: false,
+            lights_panel: false,
+            status_bar: false,
             display_ui: true,
+            camera_bookmarks_path: None,
             window: WindowId::primary(),
         }
     }
 }
+/// Configuration of the [`FlyCamera`](bevy_fly_camera::FlyCamera)s managed by the editor.
+/// Changes are applied to the cameras immediately.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlyCameraSettings {
+    pub max_speed: f32,
+    pub accel: f32,
+    pub sensitivity: f32,
+    /// The mouse button which has to be held down to look around. If `None`, moving the mouse always rotates the camera.
+    pub mouse_button: Option<MouseButton>,
+    /// Whether scrolling the mouse wheel changes the `max_speed`.
+    pub scroll_adjusts_speed: bool,
+    pub key_layout: FlyCameraKeyLayout,
+}
+impl Default for FlyCameraSettings {
+    fn default() -> Self {
+        FlyCameraSettings {
+            max_speed: 0.5,
+            accel: 1.5,
+            sensitivity: 6.0,
+            mouse_button: Some(MouseButton::Left),
+            scroll_adjusts_speed: true,
+            key_layout: FlyCameraKeyLayout::Qwerty,
+        }
+    }
+}
+
+/// Which keys move the fly camera. All layouts use the keys in the position of WASD on a QWERTY keyboard,
+/// as well as space and left shift for moving up and down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlyCameraKeyLayout {
+    Qwerty,
+    Azerty,
+    Dvorak,
+}
+impl FlyCameraKeyLayout {
+    pub const ALL: [FlyCameraKeyLayout; 3] = [
+        FlyCameraKeyLayout::Qwerty,
+        FlyCameraKeyLayout::Azerty,
+        FlyCameraKeyLayout::Dvorak,
+    ];
+
+    /// The keys for moving forward, backward, left, right, up and down.
+    pub fn keys(self) -> [KeyCode; 6] {
+        let [forward, backward, left, right] = match self {
+            FlyCameraKeyLayout::Qwerty => [KeyCode::W, KeyCode::S, KeyCode::A, KeyCode::D],
+            FlyCameraKeyLayout::Azerty => [KeyCode::Z, KeyCode::S, KeyCode::Q, KeyCode::D],
+            FlyCameraKeyLayout::Dvorak => [KeyCode::Comma, KeyCode::O, KeyCode::A, KeyCode::E],
+        };
+        [forward, backward, left, right, KeyCode::Space, KeyCode::LShift]
+    }
+}
+
+/// Configuration of the [`PanCam`](bevy_pancam::PanCam)s added to 2D cameras by the editor.
+/// Changes are applied to the cameras immediately.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PanCamSettings {
+    /// The mouse buttons which pan the camera when dragging.
+    pub grab_buttons: Vec<MouseButton>,
+    /// Multiplier for how fast scrolling zooms in and out.
+    pub zoom_speed: f32,
+    /// The smallest [`OrthographicProjection::scale`](bevy::render::camera::OrthographicProjection::scale) the camera can zoom in to.
+    pub min_scale: f32,
+    /// The largest [`OrthographicProjection::scale`](bevy::render::camera::OrthographicProjection::scale) the camera can zoom out to.
+    pub max_scale: f32,
+    /// The area in world space the view of the camera is kept inside of, where `top` is larger than `bottom`.
+    /// If the view is larger than the bounds, it gets centered on them.
+    pub bounds: Option<Rect<f32>>,
+}
+impl Default for PanCamSettings {
+    fn default() -> Self {
+        PanCamSettings {
+            grab_buttons: vec![MouseButton::Left, MouseButton::Right, MouseButton::Middle],
+            zoom_speed: 1.0,
+            min_scale: 0.05,
+            max_scale: 20.0,
+            bounds: None,
+        }
+    }
+}
+
+/// The plane the reference grid lies in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridPlane {
+    /// The ground plane of 3D scenes
+    XZ,
+    /// The plane 2D scenes are drawn in
+    XY,
+}
+
+/// Configuration of the reference grid drawn when [`EditorSettings::show_grid`] is enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridSettings {
+    /// Distance between the major grid lines.
+    pub spacing: f32,
+    /// Number of cells each major cell is divided into by the minor grid lines, `1` to only draw major lines.
+    pub subdivisions: u32,
+    pub plane: GridPlane,
+    /// Number of major cells the grid extends in each direction, if the camera doesn't see further than that.
+    pub extent: u32,
+}
+impl Default for GridSettings {
+    fn default() -> Self {
+        GridSettings {
+            spacing: 1.0,
+            subdivisions: 10,
+            plane: GridPlane::XZ,
+            extent: 20,
+        }
+    }
+}
+
 impl EditorSettings {
     pub fn new() -> Self {
         EditorSettings::default()
@@ -169,6 +397,81 @@ impl EditorSettings {
         self.drag_and_drop_handlers.push((extensions, Box::new(handler)))
     }
 
+    /// Selects the cameras which get picking, fly camera and pan camera components through the `auto_*` settings.
+    /// Fly cameras are only added to perspective cameras and pan cameras only to orthographic ones.
+    ///
+    /// By default, only the cameras named [`CAMERA_3D`](bevy::render::render_graph::base::camera::CAMERA_3D)
+    /// (picking and fly camera) and [`CAMERA_2D`](bevy::render::render_graph::base::camera::CAMERA_2D) (pan camera) are used.
+    /// Cameras with the [`EditorTarget`](crate::EditorTarget) marker are always included.
+    /// ```rust,no_run
+    /// # let mut settings = bevy_editor_pls::EditorSettings::new();
+    /// settings.filter_cameras(|camera| camera.name.as_deref() != Some("minimap"));
+    /// ```
+    pub fn filter_cameras<F>(&mut self, filter: F)
+    where
+        F: Fn(&Camera) -> bool + Send + Sync + 'static,
+    {
+        self.camera_filter = Some(Box::new(filter));
+    }
+
+    /// Whether `camera` matches the [camera filter](EditorSettings::filter_cameras),
+    /// or is called `default_name` if there is none.
+    pub(crate) fn targets_camera(&self, camera: &Camera, default_name: &str) -> bool {
+        match &self.camera_filter {
+            Some(filter) => filter(camera),
+            None => camera.name.as_deref() == Some(default_name),
+        }
+    }
+
+    /// Selects the meshes whose bounding boxes are drawn when [`bounding_boxes_filtered`](EditorSettings::bounding_boxes_filtered) is enabled.
+    /// Bounding boxes are computed from the mesh vertex positions and the entity's `GlobalTransform`.
+    /// ```rust,no_run
+    /// # struct Enemy;
+    /// # let mut settings = bevy_editor_pls::EditorSettings::new();
+    /// settings.filter_bounding_boxes(|entity| entity.contains::<Enemy>());
+    /// settings.bounding_boxes_filtered = true;
+    /// ```
+    pub fn filter_bounding_boxes<F>(&mut self, filter: F)
+    where
+        F: Fn(&EntityRef) -> bool + Send + Sync + 'static,
+    {
+        self.bounding_box_filter = Some(Box::new(filter));
+    }
+
+    /// Only shows [name labels](EditorSettings::show_labels) for the entities matching `filter`.
+    /// ```rust,no_run
+    /// # struct Enemy;
+    /// # let mut settings = bevy_editor_pls::EditorSettings::new();
+    /// settings.filter_labels(|entity| entity.contains::<Enemy>());
+    /// ```
+    pub fn filter_labels<F>(&mut self, filter: F)
+    where
+        F: Fn(&EntityRef) -> bool + Send + Sync + 'static,
+    {
+        self.label_filter = Some(Box::new(filter));
+    }
+
+    /// Includes the resource `R` in the snapshot taken when pressing **Play**,
+    /// so that it gets reset when pressing **Stop**. See [`PlayMode`](crate::PlayMode).
+    pub fn snapshot_resource<R: Component + Clone>(&mut self) {
+        self.snapshot_resources.push(Box::new(|world: &World| {
+            let resource = world.get_resource::<R>()?.clone();
+            let restore: RestoreResourceFn = Box::new(move |world: &mut World| world.insert_resource(resource));
+            Some(restore)
+        }));
+    }
+
+    /// Lets stopping [`PlayMode`](crate::PlayMode) remove components of type `C` which were added while playing.
+    ///
+    /// bevy can't remove a component knowing only its type id, so the editor needs this for every reflectable
+    /// component type of your own. The reflectable components of bevy are already known to the editor.
+    pub fn removable_component<C: Component>(&mut self) {
+        self.removable_components
+            .insert(TypeId::of::<C>(), |world: &mut World, entity: Entity| {
+                world.entity_mut(entity).remove::<C>();
+            });
+    }
+
 
     #[rustfmt::skip]
     #[allow(unused)]
@@ -0,0 +1,207 @@
+use crate::EditorSettings;
+use bevy::{
+    asset::{Asset, HandleId},
+    prelude::*,
+    utils::HashMap,
+};
+use bevy_inspector_egui::{bevy_egui::EguiContext, egui, Context, Inspectable};
+
+/// Adds an **Assets > Loaded** window listing the contents of the common asset collections.
+///
+/// For every asset it shows the handle id, the path and load state reported by the [`AssetServer`]
+/// and how many entities and materials hold a handle to it. Bevy doesn't expose the asset server's internal
+/// reference counts, so the last column only counts handles stored as components or in the fields of
+/// [`StandardMaterial`]s and [`ColorMaterial`]s.
+/// Clicking a [`StandardMaterial`] opens it in an inspector below the list.
+pub struct EditorExtensionAssets;
+impl Plugin for EditorExtensionAssets {
+    fn build(&self, app: &mut AppBuilder) {
+        let mut editor_settings = app.world_mut().get_resource_or_insert_with(EditorSettings::default);
+        add_to_editor(&mut editor_settings);
+
+        app.init_resource::<AssetsExtensionState>()
+            .add_system(loaded_assets_ui.exclusive_system());
+    }
+}
+
+fn add_to_editor(settings: &mut EditorSettings) {
+    settings.add_menu_item("Assets", |ui, world| {
+        let mut state = world.get_resource_mut::<AssetsExtensionState>().unwrap();
+        if ui.button("Loaded").clicked() {
+            state.open = true;
+        }
+    });
+}
+
+#[derive(Default)]
+struct AssetsExtensionState {
+    open: bool,
+    selected_material: Option<HandleId>,
+}
+
+struct AssetRow {
+    id: HandleId,
+    path: Option<String>,
+    load_state: String,
+    users: usize,
+}
+
+struct AssetSection {
+    name: &'static str,
+    is_material: bool,
+    rows: Vec<AssetRow>,
+}
+
+/// How many materials reference each texture.
+fn texture_users(world: &World) -> HashMap<HandleId, usize> {
+    let mut users: HashMap<HandleId, usize> = HashMap::default();
+    let mut count = |texture: &Option<Handle<Texture>>| {
+        if let Some(texture) = texture {
+            *users.entry(texture.id).or_default() += 1;
+        }
+    };
+
+    if let Some(materials) = world.get_resource::<Assets<StandardMaterial>>() {
+        for (_, material) in materials.iter() {
+            count(&material.base_color_texture);
+            count(&material.metallic_roughness_texture);
+            count(&material.normal_map);
+            count(&material.occlusion_texture);
+            count(&material.emissive_texture);
+        }
+    }
+    if let Some(materials) = world.get_resource::<Assets<ColorMaterial>>() {
+        for (_, material) in materials.iter() {
+            count(&material.texture);
+        }
+    }
+    users
+}
+
+/// Lists the assets of type `T`, `users` counts the references from other assets.
+fn collect_section<T: Asset>(
+    world: &mut World,
+    name: &'static str,
+    mut users: HashMap<HandleId, usize>,
+) -> Option<AssetSection> {
+    world.get_resource::<Assets<T>>()?;
+
+    for handle in world.query::<&Handle<T>>().iter(world) {
+        *users.entry(handle.id).or_default() += 1;
+    }
+
+    let assets = world.get_resource::<Assets<T>>().unwrap();
+    let asset_server = world.get_resource::<AssetServer>();
+
+    let mut rows: Vec<AssetRow> = assets
+        .iter()
+        .map(|(id, _)| {
+            let path = asset_server
+                .and_then(|server| server.get_handle_path(id))
+                .map(|path| match path.label() {
+                    Some(label) => format!("{}#{}", path.path().display(), label),
+                    None => path.path().display().to_string(),
+                });
+            let load_state = asset_server.map_or_else(String::new, |server| format!("{:?}", server.get_load_state(id)));
+
+            AssetRow {
+                id,
+                path,
+                load_state,
+                users: users.get(&id).copied().unwrap_or(0),
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Some(AssetSection {
+        name,
+        is_material: std::any::TypeId::of::<T>() == std::any::TypeId::of::<StandardMaterial>(),
+        rows,
+    })
+}
+
+fn loaded_assets_ui(world: &mut World) {
+    if !world.get_resource::<AssetsExtensionState>().unwrap().open {
+        return;
+    }
+
+    let texture_users = texture_users(world);
+    let sections: Vec<AssetSection> = vec![
+        collect_section::<Mesh>(world, "Meshes", HashMap::default()),
+        collect_section::<StandardMaterial>(world, "Standard Materials", HashMap::default()),
+        collect_section::<ColorMaterial>(world, "Color Materials", HashMap::default()),
+        collect_section::<Texture>(world, "Textures", texture_users),
+        collect_section::<Scene>(world, "Scenes", HashMap::default()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let world_ptr = world as *mut _;
+    let world_cell = world.cell();
+    let egui_context = world_cell.get_resource::<EguiContext>().unwrap();
+    let editor_settings = world_cell.get_resource::<EditorSettings>().unwrap();
+    let mut state = world_cell.get_resource_mut::<AssetsExtensionState>().unwrap();
+
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    let context = unsafe { Context::new_ptr(Some(ctx), world_ptr) };
+
+    let mut is_open = true;
+    egui::Window::new("Loaded Assets").open(&mut is_open).show(ctx, |ui| {
+        ui.style_mut().wrap = Some(false);
+
+        for section in &sections {
+            egui::CollapsingHeader::new(format!("{} ({})", section.name, section.rows.len()))
+                .id_source(section.name)
+                .show(ui, |ui| {
+                    egui::ScrollArea::from_max_height(200.0)
+                        .id_source(section.name)
+                        .show(ui, |ui| {
+                            egui::Grid::new(section.name).striped(true).show(ui, |ui| {
+                                ui.label("Handle");
+                                ui.label("Path");
+                                ui.label("State");
+                                ui.label("Used by").on_hover_text(
+                                    "Number of entities with a handle to the asset as a component,\n\
+                                     plus the materials using a texture. This is not the reference count:\n\
+                                     handles in resources, other assets or leaked by code aren't counted.",
+                                );
+                                ui.end_row();
+
+                                for row in &section.rows {
+                                    let id = format!("{:?}", row.id);
+                                    if section.is_material {
+                                        let selected = state.selected_material == Some(row.id);
+                                        if ui.selectable_label(selected, id).clicked() {
+                                            state.selected_material = if selected { None } else { Some(row.id) };
+                                        }
+                                    } else {
+                                        ui.label(id);
+                                    }
+                                    ui.label(row.path.as_deref().unwrap_or("-"));
+                                    ui.label(&row.load_state);
+                                    ui.label(row.users.to_string());
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                });
+        }
+
+        if let Some(id) = state.selected_material {
+            ui.separator();
+            ui.heading("Material");
+            let mut handle = Handle::<StandardMaterial>::weak(id);
+            handle.ui(ui, Default::default(), &context);
+        }
+    });
+
+    if !is_open {
+        state.open = false;
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{plugin::EditorState, EditorSettings};
+use bevy::{asset::HandleId, prelude::*};
+use bevy_inspector_egui::{bevy_egui::EguiContext, egui};
+
+/// Adds a **Utils > Material** window for editing the [`StandardMaterial`] of the currently inspected entity.
+pub struct EditorExtensionMaterial;
+impl Plugin for EditorExtensionMaterial {
+    fn build(&self, app: &mut AppBuilder) {
+        let mut editor_settings = app.world_mut().get_resource_or_insert_with(EditorSettings::default);
+        add_to_editor(&mut editor_settings);
+
+        app.init_resource::<MaterialExtensionState>().add_system(material_ui.system());
+    }
+}
+
+fn add_to_editor(settings: &mut EditorSettings) {
+    settings.add_menu_item("Utils", |ui, world| {
+        let mut state = world.get_resource_mut::<MaterialExtensionState>().unwrap();
+        if ui.button("Material").clicked() {
+            state.open = true;
+        }
+    });
+}
+
+#[derive(Default)]
+struct MaterialExtensionState {
+    open: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn material_ui(
+    egui_context: Res<EguiContext>,
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    mut extension_state: ResMut<MaterialExtensionState>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    textures: Res<Assets<Texture>>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(Option<&Name>, &mut Handle<StandardMaterial>)>,
+) {
+    if !extension_state.open {
+        return;
+    }
+
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    let mut texture_names: Vec<(HandleId, String)> = textures
+        .iter()
+        .map(|(id, _)| {
+            let name = asset_server
+                .get_handle_path(id)
+                .map_or_else(|| format!("{:?}", id), |path| path.path().display().to_string());
+            (id, name)
+        })
+        .collect();
+    texture_names.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+    let mut is_open = true;
+    egui::Window::new("Material").open(&mut is_open).show(ctx, |ui| {
+        let entity = match editor_state
+            .currently_inspected
+            .filter(|&entity| query.get_mut(entity).is_ok())
+        {
+            Some(entity) => entity,
+            None => {
+                ui.label("Inspect an entity with a StandardMaterial to edit it.");
+                return;
+            }
+        };
+
+        let material_id = query.get_mut(entity).unwrap().1.id;
+        let users = query.iter_mut().filter(|(_, handle)| handle.id == material_id).count();
+
+        let (name, mut handle) = query.get_mut(entity).unwrap();
+        ui.heading(crate::ui::name_or_entity(name, entity));
+
+        let mut edited = match materials.get(&*handle) {
+            Some(material) => duplicate(material),
+            None => {
+                ui.label("Material is not loaded yet.");
+                return;
+            }
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(format!("Used by {} entities", users));
+            if ui
+                .add(egui::Button::new("Make unique").enabled(users > 1))
+                .on_hover_text("Give this entity its own copy of the material")
+                .clicked()
+            {
+                *handle = materials.add(duplicate(&edited));
+            }
+        });
+        ui.separator();
+
+        let mut changed = false;
+        egui::Grid::new("material editor").show(ui, |ui| {
+            changed |= color_edit(ui, "Base color", &mut edited.base_color);
+            changed |= color_edit(ui, "Emissive", &mut edited.emissive);
+
+            ui.label("Roughness");
+            changed |= ui.add(egui::Slider::new(&mut edited.roughness, 0.0..=1.0)).changed();
+            ui.end_row();
+            ui.label("Metallic");
+            changed |= ui.add(egui::Slider::new(&mut edited.metallic, 0.0..=1.0)).changed();
+            ui.end_row();
+            ui.label("Reflectance");
+            changed |= ui.add(egui::Slider::new(&mut edited.reflectance, 0.0..=1.0)).changed();
+            ui.end_row();
+
+            ui.label("Unlit");
+            changed |= ui.checkbox(&mut edited.unlit, "").changed();
+            ui.end_row();
+            ui.label("Double sided");
+            changed |= ui.checkbox(&mut edited.double_sided, "").changed();
+            ui.end_row();
+        });
+
+        ui.separator();
+        ui.label("Textures");
+        let slots = vec![
+            ("Base color", &mut edited.base_color_texture),
+            ("Metallic roughness", &mut edited.metallic_roughness_texture),
+            ("Normal map", &mut edited.normal_map),
+            ("Occlusion", &mut edited.occlusion_texture),
+            ("Emissive", &mut edited.emissive_texture),
+        ];
+        for (label, slot) in slots {
+            changed |= texture_slot(ui, label, slot, &textures, &texture_names);
+        }
+
+        if changed {
+            if let Some(material) = materials.get_mut(&*handle) {
+                *material = edited;
+            }
+        }
+    });
+
+    if !is_open {
+        extension_state.open = false;
+    }
+}
+
+fn color_edit(ui: &mut egui::Ui, label: &str, color: &mut Color) -> bool {
+    ui.label(label);
+    let mut rgba = color.as_rgba_f32();
+    let changed = ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed();
+    if changed {
+        *color = Color::rgba(rgba[0], rgba[1], rgba[2], rgba[3]);
+    }
+    ui.end_row();
+    changed
+}
+
+fn texture_slot(
+    ui: &mut egui::Ui,
+    label: &str,
+    slot: &mut Option<Handle<Texture>>,
+    textures: &Assets<Texture>,
+    texture_names: &[(HandleId, String)],
+) -> bool {
+    let current = slot.as_ref().map(|handle| handle.id);
+    let current_name = match current {
+        Some(id) => texture_names
+            .iter()
+            .find(|(texture, _)| *texture == id)
+            .map_or("<not loaded>", |(_, name)| name.as_str()),
+        None => "None",
+    };
+
+    let mut changed = false;
+    egui::CollapsingHeader::new(format!("{}: {}", label, current_name))
+        .id_source(label)
+        .show(ui, |ui| {
+            if ui.selectable_label(current.is_none(), "None").clicked() {
+                *slot = None;
+                changed = true;
+            }
+            for (id, name) in texture_names {
+                if ui.selectable_label(current == Some(*id), name).clicked() {
+                    *slot = Some(textures.get_handle(*id));
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+fn duplicate(material: &StandardMaterial) -> StandardMaterial {
+    StandardMaterial {
+        base_color: material.base_color,
+        base_color_texture: material.base_color_texture.clone(),
+        roughness: material.roughness,
+        metallic: material.metallic,
+        metallic_roughness_texture: material.metallic_roughness_texture.clone(),
+        reflectance: material.reflectance,
+        normal_map: material.normal_map.clone(),
+        double_sided: material.double_sided,
+        occlusion_texture: material.occlusion_texture.clone(),
+        emissive: material.emissive,
+        emissive_texture: material.emissive_texture.clone(),
+        unlit: material.unlit,
+    }
+}
@@ -1,3 +1,7 @@
+mod assets;
+mod material;
 mod spawn;
 
+pub use assets::EditorExtensionAssets;
+pub use material::EditorExtensionMaterial;
 pub use spawn::EditorExtensionSpawn;
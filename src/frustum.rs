@@ -0,0 +1,136 @@
+use bevy::{
+    prelude::*,
+    render::camera::{ActiveCameras, Camera},
+    ui::camera::CAMERA_UI,
+    utils::HashMap,
+};
+
+use crate::{
+    camera,
+    icons::{self, EditorIcon},
+    plugin::EditorState,
+    DebugDraw, EditorSettings,
+};
+
+/// Edge length of the cube drawn at the position of cameras, which can also be clicked to inspect them.
+const ICON_SIZE: f32 = 0.3;
+/// Frustums are cut off at this distance, because the far plane is usually too far away to be useful.
+const MAX_DEPTH: f32 = 10.0;
+const FRUSTUM_COLOR: Color = Color::rgb(0.8, 0.8, 0.8);
+const SELECTED_COLOR: Color = Color::YELLOW;
+
+/// Shared mesh of the invisible icon which makes cameras pickable.
+pub(crate) struct CameraIconMesh(Handle<Mesh>);
+
+impl FromWorld for CameraIconMesh {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+        CameraIconMesh(meshes.add(Mesh::from(shape::Cube { size: ICON_SIZE })))
+    }
+}
+
+/// Marker for the icons of cameras.
+pub(crate) struct CameraIcon;
+
+/// Frustums are drawn for every camera except the UI camera and the one the editor window is rendered from.
+fn is_shown(editor_settings: &EditorSettings, viewport_camera: Option<Entity>, entity: Entity, camera: &Camera) -> bool {
+    editor_settings.show_camera_frustums && Some(entity) != viewport_camera && camera.name.as_deref() != Some(CAMERA_UI)
+}
+
+/// Spawns an icon for every camera whose frustum is drawn and despawns the other ones,
+/// so that cameras can only be clicked while their frustum is visible.
+pub(crate) fn camera_icon_system(
+    mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
+    active_cameras: Res<ActiveCameras>,
+    icon_mesh: Res<CameraIconMesh>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    camera_icons: Query<(Entity, &EditorIcon), With<CameraIcon>>,
+) {
+    let viewport_camera = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(_, camera, _)| camera.window)
+    });
+    let mut without_icon: HashMap<Entity, GlobalTransform> = cameras
+        .iter()
+        .filter(|&(entity, camera, _)| is_shown(&editor_settings, viewport_camera, entity, camera))
+        .map(|(entity, _, &transform)| (entity, transform))
+        .collect();
+    for (icon, editor_icon) in camera_icons.iter() {
+        if without_icon.remove(&editor_icon.target).is_none() {
+            commands.entity(icon).despawn();
+        }
+    }
+
+    for (camera, transform) in without_icon {
+        icons::spawn_icon(&mut commands, icon_mesh.0.clone(), camera, transform).insert(CameraIcon);
+    }
+}
+
+/// Draws the view frustum of every camera except the one the editor window is rendered from.
+pub(crate) fn frustum_system(
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    active_cameras: Res<ActiveCameras>,
+    mut debug_draw: ResMut<DebugDraw>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+) {
+    let viewport_camera = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(_, camera, _)| camera.window)
+    });
+
+    for (entity, camera, transform) in cameras.iter() {
+        if !is_shown(&editor_settings, viewport_camera, entity, camera) {
+            continue;
+        }
+
+        let color = match editor_state.currently_inspected {
+            Some(inspected) if inspected == entity => SELECTED_COLOR,
+            _ => FRUSTUM_COLOR,
+        };
+        draw_frustum(&mut debug_draw, camera, transform, color);
+    }
+}
+
+fn draw_frustum(debug_draw: &mut DebugDraw, camera: &Camera, transform: &GlobalTransform, color: Color) {
+    let inverse_projection = camera.projection_matrix.inverse();
+    let ndc_corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+
+    let mut near = [Vec3::ZERO; 4];
+    let mut far = [Vec3::ZERO; 4];
+    for (i, &(x, y)) in ndc_corners.iter().enumerate() {
+        let near_corner = inverse_projection.project_point3(Vec3::new(x, y, 0.0));
+        let far_corner = inverse_projection.project_point3(Vec3::new(x, y, 1.0));
+
+        // views look along -Z, so the depth is the negated z coordinate
+        let (near_depth, far_depth) = (-near_corner.z, -far_corner.z);
+        let far_corner = if far_depth > MAX_DEPTH && far_depth > near_depth {
+            near_corner.lerp(far_corner, (MAX_DEPTH - near_depth) / (far_depth - near_depth))
+        } else {
+            far_corner
+        };
+
+        near[i] = transform.mul_vec3(near_corner);
+        far[i] = transform.mul_vec3(far_corner);
+    }
+
+    for i in 0..4 {
+        let next = (i + 1) % 4;
+        debug_draw.line(near[i], near[next], color, 0.0);
+        debug_draw.line(far[i], far[next], color, 0.0);
+        debug_draw.line(near[i], far[i], color, 0.0);
+        debug_draw.line(transform.translation, near[i], color, 0.0);
+    }
+
+    // marks the top edge, which can't be told apart from the bottom one otherwise
+    let top_center = (far[2] + far[3]) / 2.0;
+    let up = transform.rotation * Vec3::Y * far[2].distance(far[3]) * 0.2;
+    debug_draw.line(far[2], top_center + up, color, 0.0);
+    debug_draw.line(far[3], top_center + up, color, 0.0);
+
+    let icon = Transform {
+        translation: transform.translation,
+        rotation: transform.rotation,
+        scale: Vec3::splat(ICON_SIZE),
+    };
+    debug_draw.cuboid(icon, color, 0.0);
+}
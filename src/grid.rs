@@ -0,0 +1,144 @@
+use bevy::{
+    prelude::*,
+    render::camera::{ActiveCameras, Camera, OrthographicProjection},
+};
+
+use crate::{camera, DebugDraw, EditorSettings, GridPlane, GridSettings};
+
+const MINOR_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const MAJOR_COLOR: Color = Color::rgb(0.45, 0.45, 0.45);
+pub(crate) const X_AXIS_COLOR: Color = Color::rgb(0.9, 0.2, 0.2);
+pub(crate) const Y_AXIS_COLOR: Color = Color::rgb(0.2, 0.9, 0.2);
+pub(crate) const Z_AXIS_COLOR: Color = Color::rgb(0.2, 0.4, 0.9);
+
+/// Upper limit for the number of lines drawn in each direction,
+/// the spacing of the lines grows by a factor of ten until the grid, including its subdivisions, fits into that.
+const MAX_LINES: f32 = 100.0;
+/// How far a perspective camera sees along the grid, relative to its height above it.
+const PERSPECTIVE_VIEW_DISTANCE: f32 = 20.0;
+
+impl GridPlane {
+    /// The two axes spanning the plane and its normal.
+    fn axes(self) -> (Vec3, Vec3, Vec3) {
+        match self {
+            GridPlane::XZ => (Vec3::X, Vec3::Z, Vec3::Y),
+            GridPlane::XY => (Vec3::X, Vec3::Y, Vec3::Z),
+        }
+    }
+}
+
+/// Draws the reference grid centered below the viewport camera, so that it appears to go on endlessly.
+pub(crate) fn grid_system(
+    editor_settings: Res<EditorSettings>,
+    active_cameras: Res<ActiveCameras>,
+    mut debug_draw: ResMut<DebugDraw>,
+    cameras: Query<(&Camera, &GlobalTransform, Option<&OrthographicProjection>)>,
+) {
+    let settings = &editor_settings.grid_settings;
+
+    if editor_settings.show_world_axes {
+        let length = settings.spacing;
+        debug_draw.arrow(Vec3::ZERO, Vec3::X * length, X_AXIS_COLOR, 0.0);
+        debug_draw.arrow(Vec3::ZERO, Vec3::Y * length, Y_AXIS_COLOR, 0.0);
+        debug_draw.arrow(Vec3::ZERO, Vec3::Z * length, Z_AXIS_COLOR, 0.0);
+    }
+
+    if !editor_settings.show_grid || settings.spacing <= 0.0 {
+        return;
+    }
+    let camera = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(camera, ..)| camera.window)
+    });
+    let (transform, orthographic) = match camera.and_then(|camera| cameras.get(camera).ok()) {
+        Some((_, transform, orthographic)) => (transform, orthographic),
+        None => return,
+    };
+
+    let (u, v, normal) = settings.plane.axes();
+    let view_radius = match orthographic {
+        Some(projection) => {
+            let width = projection.right - projection.left;
+            let height = projection.top - projection.bottom;
+            width.max(height) / 2.0 * projection.scale
+        }
+        None => transform.translation.dot(normal).abs() * PERSPECTIVE_VIEW_DISTANCE,
+    };
+    let radius = view_radius.max(settings.spacing * settings.extent as f32);
+
+    let (major_step, minor_step) = line_steps(settings, radius);
+    let subdivisions = settings.subdivisions.max(1);
+    let center = (transform.translation.dot(u), transform.translation.dot(v));
+
+    if subdivisions > 1 {
+        draw_lines(&mut debug_draw, (u, v), center, radius, minor_step, |i| {
+            (i % subdivisions as i64 != 0).then(|| MINOR_COLOR)
+        });
+    }
+    let v_axis_color = match settings.plane {
+        GridPlane::XZ => Z_AXIS_COLOR,
+        GridPlane::XY => Y_AXIS_COLOR,
+    };
+    draw_lines(&mut debug_draw, (u, v), center, radius, major_step, |_| Some(MAJOR_COLOR));
+    debug_draw.line(u * (center.0 - radius), u * (center.0 + radius), X_AXIS_COLOR, 0.0);
+    debug_draw.line(v * (center.1 - radius), v * (center.1 + radius), v_axis_color, 0.0);
+}
+
+/// The spacing of the major and the minor lines of a grid reaching `radius` far from its center.
+fn line_steps(settings: &GridSettings, radius: f32) -> (f32, f32) {
+    let subdivisions = settings.subdivisions.max(1) as f32;
+    let mut major_step = settings.spacing;
+    while radius * subdivisions / major_step > MAX_LINES {
+        major_step *= 10.0;
+    }
+    (major_step, major_step / subdivisions)
+}
+
+/// Draws the lines at multiples of `step` along both axes, skipping the ones through the origin
+/// and the ones `color` returns `None` for.
+fn draw_lines(
+    debug_draw: &mut DebugDraw,
+    (u, v): (Vec3, Vec3),
+    center: (f32, f32),
+    radius: f32,
+    step: f32,
+    color: impl Fn(i64) -> Option<Color>,
+) {
+    let count = (radius / step).ceil() as i64;
+    for &(axis, other, center, other_center) in [(u, v, center.0, center.1), (v, u, center.1, center.0)].iter() {
+        let first = (center / step).round() as i64 - count;
+        for i in first..=first + 2 * count {
+            let color = match color(i) {
+                Some(color) if i != 0 => color,
+                _ => continue,
+            };
+            let position = axis * (i as f32 * step);
+            let start = position + other * (other_center - radius);
+            let end = position + other * (other_center + radius);
+            debug_draw.line(start, end, color, 0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_settings_draw_minor_lines() {
+        let settings = GridSettings::default();
+        let radius = settings.spacing * settings.extent as f32;
+        let (major_step, minor_step) = line_steps(&settings, radius);
+
+        assert!(minor_step < major_step);
+        assert!(radius / minor_step <= MAX_LINES);
+    }
+
+    #[test]
+    fn steps_grow_with_the_radius() {
+        let settings = GridSettings::default();
+        let (major_step, minor_step) = line_steps(&settings, 10_000.0);
+
+        assert_eq!(major_step / minor_step, settings.subdivisions as f32);
+        assert!(10_000.0 / minor_step <= MAX_LINES);
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{X_AXIS_COLOR, Y_AXIS_COLOR, Z_AXIS_COLOR},
+    plugin::EditorState,
+    DebugDraw, EditorSettings,
+};
+
+const AXIS_LENGTH: f32 = 0.25;
+const CONNECTION_COLOR: Color = Color::rgb(0.8, 0.8, 0.8);
+
+/// Draws the local coordinate axes of the inspected entity and all of its descendants,
+/// along with lines from every parent to its children.
+pub(crate) fn hierarchy_axes_system(
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    mut debug_draw: ResMut<DebugDraw>,
+    transforms: Query<&GlobalTransform>,
+    children_query: Query<&Children>,
+) {
+    if !editor_settings.hierarchy_axes {
+        return;
+    }
+    let root = match editor_state.currently_inspected {
+        Some(root) => root,
+        None => return,
+    };
+
+    let mut stack = vec![root];
+    while let Some(entity) = stack.pop() {
+        let transform = match transforms.get(entity) {
+            Ok(transform) => transform,
+            Err(_) => continue,
+        };
+        let position = transform.translation;
+        for &(axis, color) in [(Vec3::X, X_AXIS_COLOR), (Vec3::Y, Y_AXIS_COLOR), (Vec3::Z, Z_AXIS_COLOR)].iter() {
+            debug_draw.line(position, position + transform.rotation * axis * AXIS_LENGTH, color, 0.0);
+        }
+
+        if let Ok(children) = children_query.get(entity) {
+            for &child in children.iter() {
+                if let Ok(child_transform) = transforms.get(child) {
+                    debug_draw.line(position, child_transform.translation, CONNECTION_COLOR, 0.0);
+                }
+                stack.push(child);
+            }
+        }
+    }
+}
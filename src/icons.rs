@@ -0,0 +1,44 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_mod_picking::PickableBundle;
+
+/// An invisible, pickable mesh which the editor spawns at the position of entities without a mesh of their own,
+/// like lights and cameras, so that they can be clicked like other pickable meshes.
+/// Clicking the icon inspects its `target` instead.
+pub(crate) struct EditorIcon {
+    pub target: Entity,
+}
+
+/// Spawns an icon for `target`, the mesh isn't rendered because the icon has no render pipelines.
+pub(crate) fn spawn_icon<'a, 'b>(
+    commands: &'b mut Commands<'a>,
+    mesh: Handle<Mesh>,
+    target: Entity,
+    transform: GlobalTransform,
+) -> EntityCommands<'a, 'b> {
+    let mut icon = commands.spawn_bundle((
+        mesh,
+        Transform::from(transform),
+        transform,
+        Visible::default(),
+        EditorIcon { target },
+    ));
+    icon.insert_bundle(PickableBundle::default());
+    icon
+}
+
+/// Moves every icon to its target.
+pub(crate) fn icon_follow_system(
+    mut icons: Query<(&EditorIcon, &mut Transform)>,
+    targets: Query<&GlobalTransform, Without<EditorIcon>>,
+) {
+    for (icon, mut transform) in icons.iter_mut() {
+        let target_transform = match targets.get(icon.target) {
+            Ok(&target_transform) => Transform::from(target_transform),
+            Err(_) => continue,
+        };
+        // only write on change, so that the transform propagation can skip icons of targets which don't move
+        if *transform != target_transform {
+            *transform = target_transform;
+        }
+    }
+}
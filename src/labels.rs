@@ -0,0 +1,82 @@
+use bevy::{
+    ecs::world::EntityRef,
+    prelude::*,
+    render::camera::{ActiveCameras, Camera},
+};
+use bevy_inspector_egui::{bevy_egui::EguiContext, egui};
+
+use crate::{camera, debug_draw::DebugDrawMesh, icons::EditorIcon, plugin::EditorState, ui, EditorSettings};
+
+pub(crate) type LabelFilterFn = Box<dyn Fn(&EntityRef) -> bool + Send + Sync>;
+
+/// Draws the name of every entity in view next to it, clicking a label inspects the entity.
+pub(crate) fn name_labels_system(world: &mut World) {
+    let mut query =
+        world.query_filtered::<(Entity, &GlobalTransform), (Without<Node>, Without<DebugDrawMesh>, Without<EditorIcon>)>();
+
+    let editor_settings = world.get_resource::<EditorSettings>().unwrap();
+    if !editor_settings.show_labels || !editor_settings.display_ui {
+        return;
+    }
+
+    let active_cameras = world.get_resource::<ActiveCameras>().unwrap();
+    let viewport_camera = camera::viewport_camera(editor_settings, active_cameras, |entity| {
+        world.get::<Camera>(entity).map(|camera| camera.window)
+    });
+    let viewport_camera = match viewport_camera {
+        Some(viewport_camera) => viewport_camera,
+        None => return,
+    };
+    let camera = world.get::<Camera>(viewport_camera).unwrap();
+    let camera_transform = match world.get::<GlobalTransform>(viewport_camera) {
+        Some(camera_transform) => camera_transform,
+        None => return,
+    };
+    let windows = world.get_resource::<Windows>().unwrap();
+    let window_height = match windows.get(camera.window) {
+        Some(window) => window.height(),
+        None => return,
+    };
+
+    let max_distance = editor_settings.label_max_distance;
+    let mut labels = Vec::new();
+    for (entity, transform) in query.iter(world) {
+        if entity == viewport_camera || transform.translation.distance(camera_transform.translation) > max_distance {
+            continue;
+        }
+        if let Some(filter) = &editor_settings.label_filter {
+            if !filter(&world.entity(entity)) {
+                continue;
+            }
+        }
+        if let Some(position) = camera.world_to_screen(windows, camera_transform, transform.translation) {
+            // bevy's screen space starts at the bottom of the window, egui's at the top
+            let position = egui::pos2(position.x, window_height - position.y);
+            labels.push((entity, ui::entity_name(world, entity), position));
+        }
+    }
+
+    let egui_context = world.get_resource::<EguiContext>().unwrap();
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+    let currently_inspected = world.get_resource::<EditorState>().unwrap().currently_inspected;
+
+    let mut clicked = None;
+    for (entity, name, position) in labels {
+        egui::Area::new(("editor label", entity))
+            .fixed_pos(position)
+            .order(egui::Order::Background)
+            .show(ctx, |ui| {
+                ui.style_mut().wrap = Some(false);
+                if ui.selectable_label(currently_inspected == Some(entity), name).clicked() {
+                    clicked = Some(entity);
+                }
+            });
+    }
+
+    if let Some(entity) = clicked {
+        world.get_resource_mut::<EditorState>().unwrap().currently_inspected = Some(entity);
+    }
+}
@@ -4,6 +4,9 @@
 //! - you can also enable **click to select**.
 //!   For that to work, you need to tag your camera with [PickingCameraBundle](bevy_mod_picking::PickingCameraBundle) and your meshes with [PickableBundle](bevy_mod_picking::PickableBundle), see the [example] for a full demo.
 //! - switch to app states you have registered using [`EditorSettings::add_state`]
+//! - play the game from a snapshot of the world and [restore it](PlayMode) when stopping
+//! - pause, resume, single-step and slow down the game. Gate your own systems with [`run_if_playing`] or read the delta from [`EditorTime`].
+//! - draw debug lines, boxes, spheres and arrows from any system using the [`DebugDraw`] resource
 //!
 //! ```rust,no_run
 //! use bevy::prelude::*;
@@ -50,35 +53,113 @@
 //! [example]: https://github.com/jakobhellermann/bevy-editor-pls/blob/main/examples/main.rs
 
 mod action;
+mod bookmarks;
+mod bounds;
+mod camera;
+mod debug_draw;
 mod drag_and_drop;
 mod editor_settings;
 pub mod extensions;
+mod frustum;
+mod grid;
+mod hierarchy_axes;
+mod icons;
+mod labels;
+mod lights;
+mod look_through;
+mod normals;
+mod pan_cam;
+mod play_mode;
 mod plugin;
 mod second_window_plugin;
+mod status_bar;
 mod systems;
+mod time;
 mod ui;
 mod utils;
+mod view;
 
 pub use bevy_fly_camera;
 pub use bevy_input_actionmap;
 pub use bevy_mod_picking;
 
 pub use action::EditorAction;
-pub use editor_settings::EditorSettings;
+pub use camera::EditorTarget;
+pub use debug_draw::DebugDraw;
+pub use editor_settings::{EditorSettings, FlyCameraKeyLayout, FlyCameraSettings, GridPlane, GridSettings, PanCamSettings};
+pub use play_mode::PlayMode;
 pub use plugin::EditorPlugin;
 pub use second_window_plugin::EditorPluginSecondWindow;
+pub use time::{run_if_playing, EditorTime};
+pub use view::ViewPreset;
 
 use bevy::prelude::*;
 use bevy_input_actionmap::InputMap;
 /// Sets up the default keybindings for the editor.
 ///
 /// * `Ctrl + F`: toggle the fly camera
+/// * `Ctrl + E`: toggle the dedicated editor camera
+/// * `Ctrl + G`: toggle the reference grid, `Alt + G` the world axes
+/// * `Ctrl + T`: toggle following the inspected entity with the camera
+/// * `Ctrl + L`: toggle mouse-look for the fly camera (holding the right mouse button works as well)
 /// * `Ctrl + W`: toggle the world inspector
+/// * `Alt + W`: toggle the wireframe of the inspected entity
 /// * `Ctrl + P`: toggle the performance panel
 /// * `Ctrl + Esc`: toggle whether the editor UI should be displayed
+/// * `Ctrl + -`: halve the time scale
+/// * `Ctrl + =`: double the time scale
+/// * `Ctrl + 1..9`: save the camera pose into a bookmark
+/// * `1..9`: move the camera to a saved bookmark
+/// * `Numpad 7`, `Numpad 1`, `Numpad 3`: top, front and right view, with `Ctrl` for bottom, back and left
+/// * `Numpad 5`: toggle between perspective and orthographic projection
+/// * `Home`: reset the pan and zoom of 2D cameras
+/// * `Ctrl + Numpad 0`: look through the inspected camera, `Numpad 0` to return to the editor camera
+/// * `Ctrl + Alt + Numpad 0`: move the inspected camera to the current view and look through it
 pub fn setup_default_keybindings(mut input: ResMut<InputMap<EditorAction>>) {
     input.bind(EditorAction::ToggleFlycam, vec![KeyCode::LControl, KeyCode::F]);
+    input.bind(EditorAction::ToggleEditorCamera, vec![KeyCode::LControl, KeyCode::E]);
+    input.bind(EditorAction::ToggleMouseLook, vec![KeyCode::LControl, KeyCode::L]);
+    input.bind(EditorAction::ToggleFollowSelected, vec![KeyCode::LControl, KeyCode::T]);
+    input.bind(EditorAction::ToggleGrid, vec![KeyCode::LControl, KeyCode::G]);
+    input.bind(EditorAction::ToggleWorldAxes, vec![KeyCode::LAlt, KeyCode::G]);
     input.bind(EditorAction::TogglePerformancePanel, vec![KeyCode::LControl, KeyCode::P]);
     input.bind(EditorAction::ToggleWorldInspector, vec![KeyCode::LControl, KeyCode::W]);
+    input.bind(EditorAction::ToggleWireframeSelection, vec![KeyCode::LAlt, KeyCode::W]);
     input.bind(EditorAction::ToggleEditorUi, vec![KeyCode::LControl, KeyCode::Escape]);
+    input.bind(EditorAction::HalveTimeScale, vec![KeyCode::LControl, KeyCode::Minus]);
+    input.bind(EditorAction::DoubleTimeScale, vec![KeyCode::LControl, KeyCode::Equals]);
+
+    let number_keys = [
+        KeyCode::Key1,
+        KeyCode::Key2,
+        KeyCode::Key3,
+        KeyCode::Key4,
+        KeyCode::Key5,
+        KeyCode::Key6,
+        KeyCode::Key7,
+        KeyCode::Key8,
+        KeyCode::Key9,
+    ];
+    for (slot, &key) in (1..).zip(number_keys.iter()) {
+        input.bind(EditorAction::SaveCameraBookmark(slot), vec![KeyCode::LControl, key]);
+        input.bind(EditorAction::LoadCameraBookmark(slot), vec![key]);
+    }
+
+    let view_keys = [
+        (ViewPreset::Top, ViewPreset::Bottom, KeyCode::Numpad7),
+        (ViewPreset::Front, ViewPreset::Back, KeyCode::Numpad1),
+        (ViewPreset::Right, ViewPreset::Left, KeyCode::Numpad3),
+    ];
+    for &(view, opposite, key) in view_keys.iter() {
+        input.bind(EditorAction::SetView(view), vec![key]);
+        input.bind(EditorAction::SetView(opposite), vec![KeyCode::LControl, key]);
+    }
+    input.bind(EditorAction::ToggleOrthographic, vec![KeyCode::Numpad5]);
+    input.bind(EditorAction::Reset2dView, vec![KeyCode::Home]);
+    input.bind(EditorAction::ReturnToEditorCamera, vec![KeyCode::Numpad0]);
+    input.bind(EditorAction::LookThroughSelected, vec![KeyCode::LControl, KeyCode::Numpad0]);
+    input.bind(
+        EditorAction::AlignSelectedToView,
+        vec![KeyCode::LControl, KeyCode::LAlt, KeyCode::Numpad0],
+    );
 }
@@ -0,0 +1,146 @@
+use bevy::{pbr::AmbientLight, prelude::*, utils::HashMap};
+use bevy_inspector_egui::{bevy_egui::EguiContext, egui};
+
+use crate::{
+    icons::{self, EditorIcon},
+    plugin::EditorState,
+    DebugDraw, EditorSettings,
+};
+
+/// Radius of the sphere drawn at the position of lights, which can also be clicked to inspect them.
+const ICON_RADIUS: f32 = 0.2;
+const DIRECTION_LENGTH: f32 = 1.0;
+const SELECTED_COLOR: Color = Color::YELLOW;
+
+/// Shared mesh of the invisible icon which makes lights pickable.
+pub(crate) struct LightIconMesh(Handle<Mesh>);
+
+impl FromWorld for LightIconMesh {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+        let mesh = meshes.add(Mesh::from(shape::Icosphere {
+            radius: ICON_RADIUS,
+            subdivisions: 1,
+        }));
+        LightIconMesh(mesh)
+    }
+}
+
+/// Marker for the icons of lights.
+pub(crate) struct LightIcon;
+
+/// Spawns an icon for every light without a mesh of its own while lights are shown,
+/// and despawns the icons of lights which are gone or when lights are hidden.
+pub(crate) fn light_icon_system(
+    mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
+    icon_mesh: Res<LightIconMesh>,
+    lights: Query<(Entity, &GlobalTransform), (With<Light>, Without<Handle<Mesh>>)>,
+    light_icons: Query<(Entity, &EditorIcon), With<LightIcon>>,
+) {
+    let mut without_icon: HashMap<Entity, GlobalTransform> = lights
+        .iter()
+        .filter(|_| editor_settings.show_lights)
+        .map(|(entity, &transform)| (entity, transform))
+        .collect();
+    for (icon, editor_icon) in light_icons.iter() {
+        if without_icon.remove(&editor_icon.target).is_none() {
+            commands.entity(icon).despawn();
+        }
+    }
+
+    for (light, transform) in without_icon {
+        icons::spawn_icon(&mut commands, icon_mesh.0.clone(), light, transform).insert(LightIcon);
+    }
+}
+
+pub(crate) fn draw_lights_system(
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    mut debug_draw: ResMut<DebugDraw>,
+    query: Query<(Entity, &Light, &GlobalTransform)>,
+) {
+    if !editor_settings.show_lights {
+        return;
+    }
+
+    for (entity, light, transform) in query.iter() {
+        let position = transform.translation;
+        let color = light.color;
+        debug_draw.sphere(position, ICON_RADIUS, color, 0.0);
+        for &axis in [Vec3::X, Vec3::Y, Vec3::Z].iter() {
+            let ray = axis * ICON_RADIUS * 2.0;
+            debug_draw.line(position - ray, position + ray, color, 0.0);
+        }
+
+        let forward = transform.rotation * -Vec3::Z;
+        debug_draw.arrow(position, position + forward * DIRECTION_LENGTH, color, 0.0);
+
+        let range_color = match editor_state.currently_inspected {
+            Some(inspected) if inspected == entity => SELECTED_COLOR,
+            _ => color,
+        };
+        debug_draw.sphere(position, light.range, range_color, 0.0);
+    }
+}
+
+pub(crate) fn lights_panel(
+    egui_context: Res<EguiContext>,
+    mut editor_settings: ResMut<EditorSettings>,
+    mut editor_state: ResMut<EditorState>,
+    ambient_light: Option<ResMut<AmbientLight>>,
+    mut lights: Query<(Entity, &mut Light, Option<&Name>)>,
+) {
+    if !editor_settings.lights_panel {
+        return;
+    }
+
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    egui::Window::new("Lights")
+        .open(&mut editor_settings.lights_panel)
+        .resizable(false)
+        .show(ctx, |ui| {
+            if let Some(mut ambient_light) = ambient_light {
+                egui::Grid::new("ambient light").show(ui, |ui| {
+                    ui.label("Ambient color");
+                    color_edit(ui, &mut ambient_light.color);
+                    ui.end_row();
+                    ui.label("Ambient brightness");
+                    ui.add(egui::Slider::new(&mut ambient_light.brightness, 0.0..=1.0));
+                    ui.end_row();
+                });
+                ui.separator();
+            }
+
+            egui::Grid::new("lights").show(ui, |ui| {
+                ui.label("Light");
+                ui.label("Color");
+                ui.label("Intensity");
+                ui.label("Range");
+                ui.end_row();
+
+                for (entity, mut light, name) in lights.iter_mut() {
+                    let name = crate::ui::name_or_entity(name, entity);
+                    let selected = editor_state.currently_inspected == Some(entity);
+                    if ui.selectable_label(selected, name).clicked() {
+                        editor_state.currently_inspected = Some(entity);
+                    }
+                    color_edit(ui, &mut light.color);
+                    ui.add(egui::DragValue::new(&mut light.intensity).clamp_range(0.0..=f32::MAX));
+                    ui.add(egui::DragValue::new(&mut light.range).speed(0.1).clamp_range(0.0..=f32::MAX));
+                    ui.end_row();
+                }
+            });
+        });
+}
+
+fn color_edit(ui: &mut egui::Ui, color: &mut Color) {
+    let mut rgb = [color.r(), color.g(), color.b()];
+    if ui.color_edit_button_rgb(&mut rgb).changed() {
+        *color = Color::rgb(rgb[0], rgb[1], rgb[2]);
+    }
+}
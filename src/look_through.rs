@@ -0,0 +1,149 @@
+use bevy::{
+    prelude::*,
+    render::camera::{ActiveCameras, Camera},
+};
+use bevy_fly_camera::FlyCamera;
+use bevy_input_actionmap::InputMap;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+use bevy_orbit_controls::OrbitCamera;
+
+use crate::{camera, plugin::EditorState, utils, EditorAction, EditorSettings};
+
+/// The camera the viewport renders from after [`EditorAction::LookThroughSelected`],
+/// along with what to restore on [`EditorAction::ReturnToEditorCamera`].
+#[derive(Default)]
+pub(crate) struct LookThrough {
+    active: Option<ActiveLookThrough>,
+}
+
+struct ActiveLookThrough {
+    camera: Entity,
+    /// Name of the [`ActiveCameras`] entry the camera was put into
+    slot: String,
+    previous: Option<Entity>,
+}
+
+impl LookThrough {
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+}
+
+fn return_to_editor_camera(
+    look_through: &mut LookThrough,
+    active_cameras: &mut ActiveCameras,
+    exists: impl Fn(Entity) -> bool,
+) {
+    if let Some(ActiveLookThrough { slot, previous, .. }) = look_through.active.take() {
+        if let Some(active_camera) = active_cameras.get_mut(&slot) {
+            active_camera.entity = previous.filter(|&entity| exists(entity));
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn look_through_system(
+    input: Res<InputMap<EditorAction>>,
+    editor_settings: Res<EditorSettings>,
+    egui_context: Res<EguiContext>,
+    editor_state: Res<EditorState>,
+    mut look_through: ResMut<LookThrough>,
+    mut active_cameras: ResMut<ActiveCameras>,
+    cameras: Query<&Camera>,
+    global_transforms: Query<&GlobalTransform>,
+    mut selected: Query<
+        (
+            &mut Transform,
+            Option<&Parent>,
+            Option<&mut FlyCamera>,
+            Option<&mut OrbitCamera>,
+        ),
+        With<Camera>,
+    >,
+) {
+    let exists = |entity| cameras.get(entity).is_ok();
+
+    // the camera we were looking through got despawned
+    if let Some(active) = &look_through.active {
+        if !exists(active.camera) {
+            return_to_editor_camera(&mut look_through, &mut active_cameras, exists);
+        }
+    }
+
+    if utils::ui_wants_keyboard_input(&egui_context, &editor_settings) {
+        return;
+    }
+
+    if input.just_active(EditorAction::ReturnToEditorCamera) {
+        return_to_editor_camera(&mut look_through, &mut active_cameras, exists);
+    }
+
+    let selected_camera = editor_state.currently_inspected.filter(|&entity| exists(entity));
+
+    if input.just_active(EditorAction::AlignSelectedToView) {
+        let view = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+            cameras.get(entity).ok().map(|camera| camera.window)
+        });
+        let view_transform = view.and_then(|view| global_transforms.get(view).ok());
+        match (selected_camera, view_transform) {
+            (Some(selected_camera), Some(view_transform)) if view != Some(selected_camera) => {
+                let parent_transform = match selected.get_mut(selected_camera) {
+                    Ok((_, Some(parent), _, _)) => global_transforms.get(parent.0).ok().copied(),
+                    _ => None,
+                };
+                let (mut transform, _, fly_camera, orbit_camera) = selected.get_mut(selected_camera).unwrap();
+
+                let local = match parent_transform {
+                    Some(parent) => parent.compute_matrix().inverse() * view_transform.compute_matrix(),
+                    None => view_transform.compute_matrix(),
+                };
+                *transform = Transform {
+                    scale: transform.scale,
+                    ..Transform::from_matrix(local)
+                };
+
+                if let Some(mut fly_camera) = fly_camera {
+                    camera::sync_fly_camera(&mut fly_camera, transform.rotation);
+                }
+                if let Some(mut orbit_camera) = orbit_camera {
+                    camera::sync_orbit_camera(&mut orbit_camera, &transform);
+                }
+            }
+            _ => warn!("select a camera other than the one in the viewport to align it to the view"),
+        }
+    }
+
+    if input.just_active(EditorAction::LookThroughSelected) {
+        let selected_camera = match selected_camera {
+            Some(selected_camera) => selected_camera,
+            None => {
+                warn!("select a camera to look through it");
+                return;
+            }
+        };
+        if look_through.active.as_ref().map(|active| active.camera) == Some(selected_camera) {
+            return;
+        }
+        return_to_editor_camera(&mut look_through, &mut active_cameras, exists);
+
+        let view = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+            cameras.get(entity).ok().map(|camera| camera.window)
+        });
+        let slot = active_cameras
+            .iter()
+            .find(|active_camera| view.is_some() && active_camera.entity == view)
+            .map(|active_camera| active_camera.name.clone());
+        let slot = match slot {
+            Some(slot) => slot,
+            None => return,
+        };
+        if let Some(active_camera) = active_cameras.get_mut(&slot) {
+            let previous = active_camera.entity.replace(selected_camera);
+            look_through.active = Some(ActiveLookThrough {
+                camera: selected_camera,
+                slot,
+                previous,
+            });
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::{prelude::*, render::mesh::VertexAttributeValues};
+
+use crate::{debug_draw::DebugDrawMesh, icons::EditorIcon, plugin::EditorState, DebugDraw, EditorSettings};
+
+const NORMAL_COLOR: Color = Color::rgb(0.3, 0.5, 1.0);
+const TANGENT_COLOR: Color = Color::rgb(1.0, 0.3, 0.3);
+/// Upper limit for the number of vertices whose normals are drawn in one frame, to keep large scenes responsive.
+/// Meshes which don't fit into it anymore are skipped.
+const MAX_VERTICES: usize = 100_000;
+
+fn float3_attribute<'a>(mesh: &'a Mesh, name: &'static str) -> Option<&'a [[f32; 3]]> {
+    match mesh.attribute(name)? {
+        VertexAttributeValues::Float3(values) => Some(values.as_slice()),
+        _ => None,
+    }
+}
+
+/// Draws the vertex normals, and tangents if the mesh has them, of the inspected mesh or all meshes.
+pub(crate) fn normals_system(
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    meshes: Res<Assets<Mesh>>,
+    mut debug_draw: ResMut<DebugDraw>,
+    query: Query<(Entity, &Handle<Mesh>, &GlobalTransform), (Without<DebugDrawMesh>, Without<EditorIcon>)>,
+    mut warned: Local<bool>,
+) {
+    let show_all = editor_settings.normals_all;
+    let selected = editor_state.currently_inspected.filter(|_| editor_settings.normals_selected);
+    if !show_all && selected.is_none() {
+        return;
+    }
+    let length = editor_settings.normals_length;
+
+    let mut vertices = 0;
+    for (entity, mesh, transform) in query.iter() {
+        if !show_all && selected != Some(entity) {
+            continue;
+        }
+        let mesh = match meshes.get(mesh) {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        let (positions, normals) = match (
+            float3_attribute(mesh, Mesh::ATTRIBUTE_POSITION),
+            float3_attribute(mesh, Mesh::ATTRIBUTE_NORMAL),
+        ) {
+            (Some(positions), Some(normals)) => (positions, normals),
+            _ => continue,
+        };
+        let tangents = match mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+            Some(VertexAttributeValues::Float4(tangents)) => Some(tangents),
+            _ => None,
+        };
+
+        if vertices + positions.len() > MAX_VERTICES {
+            if !*warned {
+                warn!("not drawing the normals of more than {} vertices", MAX_VERTICES);
+                *warned = true;
+            }
+            continue;
+        }
+        vertices += positions.len();
+
+        for (i, (&position, &normal)) in positions.iter().zip(normals).enumerate() {
+            let position = transform.mul_vec3(position.into());
+
+            // normals are transformed by the inverse transpose of the model matrix, which undoes the scale instead
+            let normal = (transform.rotation * (Vec3::from(normal) / transform.scale)).normalize_or_zero();
+            debug_draw.line(position, position + normal * length, NORMAL_COLOR, 0.0);
+
+            if let Some(&[x, y, z, _]) = tangents.and_then(|tangents| tangents.get(i)) {
+                let tangent = (transform.rotation * (Vec3::new(x, y, z) * transform.scale)).normalize_or_zero();
+                debug_draw.line(position, position + tangent * length, TANGENT_COLOR, 0.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::{
+    prelude::*,
+    render::camera::{Camera, CameraProjection, OrthographicProjection},
+};
+use bevy_input_actionmap::InputMap;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+use bevy_pancam::PanCam;
+
+use crate::{camera::CameraTransition, utils, EditorAction, EditorSettings, PanCamSettings};
+
+/// Tracks a [`PanCam`] added by the editor, so that its zoom can be limited and its view reset.
+pub(crate) struct ManagedPanCam {
+    initial_translation: Vec3,
+    initial_scale: f32,
+    last_scale: f32,
+}
+
+impl ManagedPanCam {
+    pub fn new(transform: &Transform, projection: &OrthographicProjection) -> Self {
+        ManagedPanCam {
+            initial_translation: transform.translation,
+            initial_scale: projection.scale,
+            last_scale: projection.scale,
+        }
+    }
+}
+
+pub(crate) fn pan_cam(settings: &PanCamSettings) -> PanCam {
+    PanCam {
+        grab_buttons: settings.grab_buttons.clone(),
+        ..Default::default()
+    }
+}
+
+/// Applies the zoom speed, zoom limits and bounds of the [`PanCamSettings`] after [`PanCam`] moved the camera.
+pub(crate) fn pan_cam_limits_system(
+    editor_settings: Res<EditorSettings>,
+    mut query: Query<(
+        &mut ManagedPanCam,
+        &mut PanCam,
+        &mut Camera,
+        &mut Transform,
+        &mut OrthographicProjection,
+        Option<&CameraTransition>,
+    )>,
+) {
+    let settings = &editor_settings.pan_cam_settings;
+
+    for (mut managed, mut pan_cam, mut camera, mut transform, mut projection, transition) in query.iter_mut() {
+        if editor_settings.is_changed() && pan_cam.grab_buttons != settings.grab_buttons {
+            pan_cam.grab_buttons = settings.grab_buttons.clone();
+        }
+
+        let mut scale = projection.scale;
+        // transitions interpolate the scale themselves and shouldn't be sped up
+        if transition.is_none() && managed.last_scale > 0.0 && scale != managed.last_scale {
+            scale = managed.last_scale * (scale / managed.last_scale).powf(settings.zoom_speed);
+        }
+        scale = scale.max(settings.min_scale).min(settings.max_scale);
+        if scale != projection.scale {
+            projection.scale = scale;
+            camera.projection_matrix = projection.get_projection_matrix();
+        }
+        managed.last_scale = scale;
+
+        if let Some(bounds) = settings.bounds {
+            let half_width = (projection.right - projection.left) / 2.0 * scale;
+            let half_height = (projection.top - projection.bottom) / 2.0 * scale;
+            let x = clamp_to_bounds(transform.translation.x, half_width, bounds.left, bounds.right);
+            let y = clamp_to_bounds(transform.translation.y, half_height, bounds.bottom, bounds.top);
+            if x != transform.translation.x || y != transform.translation.y {
+                transform.translation.x = x;
+                transform.translation.y = y;
+            }
+        }
+    }
+}
+
+/// Keeps a view of `half_extent` around `center` inside of `min..max`, or centers it if the view is larger than that.
+fn clamp_to_bounds(center: f32, half_extent: f32, min: f32, max: f32) -> f32 {
+    if max - min < half_extent * 2.0 {
+        (min + max) / 2.0
+    } else {
+        center.clamp(min + half_extent, max - half_extent)
+    }
+}
+
+pub(crate) fn reset_2d_view_system(
+    mut commands: Commands,
+    input: Res<InputMap<EditorAction>>,
+    editor_settings: Res<EditorSettings>,
+    egui_context: Res<EguiContext>,
+    query: Query<(Entity, &Camera, &ManagedPanCam, &Transform, &OrthographicProjection)>,
+) {
+    if !input.just_active(EditorAction::Reset2dView) || utils::ui_wants_keyboard_input(&egui_context, &editor_settings) {
+        return;
+    }
+
+    for (entity, camera, managed, transform, projection) in query.iter() {
+        if camera.window != editor_settings.window {
+            continue;
+        }
+
+        let target = Transform {
+            translation: managed.initial_translation,
+            ..*transform
+        };
+        commands.entity(entity).insert(CameraTransition::new(
+            *transform,
+            Some(projection.scale),
+            target,
+            Some(managed.initial_scale),
+        ));
+    }
+}
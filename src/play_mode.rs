@@ -0,0 +1,263 @@
+use std::any::TypeId;
+
+use bevy::{
+    core::Stopwatch,
+    ecs::{
+        entity::EntityMap,
+        reflect::{ReflectComponent, ReflectMapEntities},
+    },
+    prelude::*,
+    reflect::TypeRegistryArc,
+    render::{
+        camera::{Camera, OrthographicProjection, PerspectiveProjection, RenderLayers, VisibleEntities},
+        draw::OutsideFrustum,
+        render_graph::base::MainPass,
+        wireframe::Wireframe,
+    },
+    ui::{Node, Style},
+    utils::{HashMap, HashSet},
+};
+
+use crate::{
+    camera::EditorCamera, debug_draw::DebugDrawMesh, editor_settings::RestoreResourceFn, icons::EditorIcon, EditorSettings,
+    EditorTime,
+};
+
+/// Tracks whether the game was started using the **Play** button of the editor.
+///
+/// Entering play mode snapshots every reflectable component of every entity, as well as the resources registered
+/// with [`EditorSettings::snapshot_resource`]. Stopping restores the world to that snapshot:
+/// entities spawned while playing are despawned, despawned ones are respawned and the snapshotted components are
+/// written back. Reflectable components added while playing are removed again, for your own component types this
+/// needs [`EditorSettings::removable_component`].
+///
+/// Some state can't be restored:
+/// - components which aren't registered in the type registry, like mesh and material handles, are left untouched.
+///   Entities which were despawned while playing only get their reflectable components back,
+///   so a respawned mesh or sprite doesn't render anymore.
+/// - entity references outside of the hierarchy and components implementing `MapEntities` still point to
+///   the old entity if it was respawned.
+///
+/// Entities belonging to the editor, like its dedicated camera, are neither snapshotted nor restored.
+/// Game cameras marked with [`EditorTarget`](crate::EditorTarget) are snapshotted like any other entity.
+#[derive(Default)]
+pub struct PlayMode {
+    snapshot: Option<WorldSnapshot>,
+    request: Option<PlayModeRequest>,
+}
+
+struct WorldSnapshot {
+    scene: DynamicScene,
+    entities: Vec<Entity>,
+    resources: Vec<RestoreResourceFn>,
+}
+
+enum PlayModeRequest {
+    Play,
+    Stop,
+}
+
+impl PlayMode {
+    pub fn is_playing(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Snapshots the world and resumes the game at the start of the next frame.
+    pub fn play(&mut self) {
+        self.request = Some(PlayModeRequest::Play);
+    }
+
+    /// Restores the world from the snapshot and pauses the game at the start of the next frame.
+    pub fn stop(&mut self) {
+        self.request = Some(PlayModeRequest::Stop);
+    }
+
+    pub fn toggle(&mut self) {
+        if self.is_playing() {
+            self.stop();
+        } else {
+            self.play();
+        }
+    }
+}
+
+pub(crate) fn play_mode_system(world: &mut World) {
+    let mut play_mode = world.get_resource_mut::<PlayMode>().unwrap();
+    let request = match play_mode.request.take() {
+        Some(request) => request,
+        None => return,
+    };
+
+    match request {
+        PlayModeRequest::Play if !play_mode.is_playing() => {
+            let snapshot = take_snapshot(world);
+            world.get_resource_mut::<PlayMode>().unwrap().snapshot = Some(snapshot);
+            world.get_resource_mut::<EditorTime>().unwrap().resume();
+        }
+        PlayModeRequest::Stop => {
+            if let Some(snapshot) = play_mode.snapshot.take() {
+                restore_snapshot(world, snapshot);
+                world.get_resource_mut::<EditorTime>().unwrap().pause();
+            }
+        }
+        PlayModeRequest::Play => {}
+    }
+}
+
+/// Whether `entity` belongs to the editor and should keep its state when stopping play mode.
+fn is_editor_entity(world: &World, entity: Entity) -> bool {
+    let entity = world.entity(entity);
+    entity.contains::<EditorCamera>() || entity.contains::<DebugDrawMesh>() || entity.contains::<EditorIcon>()
+}
+
+/// Registers the reflectable components of bevy with [`EditorSettings::removable_component`].
+pub(crate) fn add_bevy_removable_components(editor_settings: &mut EditorSettings) {
+    editor_settings.removable_component::<Transform>();
+    editor_settings.removable_component::<GlobalTransform>();
+    editor_settings.removable_component::<Parent>();
+    editor_settings.removable_component::<PreviousParent>();
+    editor_settings.removable_component::<Children>();
+    editor_settings.removable_component::<Name>();
+    editor_settings.removable_component::<Labels>();
+    editor_settings.removable_component::<Timer>();
+    editor_settings.removable_component::<Stopwatch>();
+    editor_settings.removable_component::<Light>();
+    editor_settings.removable_component::<Camera>();
+    editor_settings.removable_component::<PerspectiveProjection>();
+    editor_settings.removable_component::<OrthographicProjection>();
+    editor_settings.removable_component::<VisibleEntities>();
+    editor_settings.removable_component::<RenderLayers>();
+    editor_settings.removable_component::<Visible>();
+    editor_settings.removable_component::<OutsideFrustum>();
+    editor_settings.removable_component::<Draw>();
+    editor_settings.removable_component::<RenderPipelines>();
+    editor_settings.removable_component::<MainPass>();
+    editor_settings.removable_component::<Wireframe>();
+    editor_settings.removable_component::<Node>();
+    editor_settings.removable_component::<Style>();
+}
+
+fn take_snapshot(world: &mut World) -> WorldSnapshot {
+    let entities: Vec<Entity> = world
+        .query::<Entity>()
+        .iter(world)
+        .filter(|&entity| !is_editor_entity(world, entity))
+        .collect();
+    let entity_ids: HashSet<u32> = entities.iter().map(|entity| entity.id()).collect();
+
+    let type_registry = world.get_resource::<TypeRegistryArc>().unwrap();
+    let mut scene = DynamicScene::from_world(world, type_registry);
+    scene
+        .entities
+        .retain(|scene_entity| entity_ids.contains(&scene_entity.entity));
+
+    let editor_settings = world.get_resource::<EditorSettings>().unwrap();
+    let resources = editor_settings
+        .snapshot_resources
+        .iter()
+        .filter_map(|snapshot| snapshot(world))
+        .collect();
+
+    WorldSnapshot {
+        scene,
+        entities,
+        resources,
+    }
+}
+
+fn restore_snapshot(world: &mut World, snapshot: WorldSnapshot) {
+    let snapshot_entities: HashSet<Entity> = snapshot.entities.iter().copied().collect();
+    let spawned_while_playing: Vec<Entity> = world
+        .query::<Entity>()
+        .iter(world)
+        .filter(|entity| !snapshot_entities.contains(entity) && !is_editor_entity(world, *entity))
+        .collect();
+    // the hierarchy components of every snapshotted entity get replaced below, so no `Children` keeps pointing to these
+    for entity in spawned_while_playing {
+        world.despawn(entity);
+    }
+
+    // entities which still exist map to themselves, despawned ones get respawned
+    let mut entity_map = EntityMap::default();
+    let mut respawned = 0;
+    for &entity in &snapshot.entities {
+        let target = match world.get_entity(entity) {
+            Some(_) => entity,
+            None => {
+                respawned += 1;
+                world.spawn().id()
+            }
+        };
+        entity_map.insert(entity, target);
+    }
+    if respawned > 0 {
+        warn!(
+            "respawned {} entities which were despawned while playing, only their reflectable components are restored",
+            respawned
+        );
+    }
+    let entities_by_id: HashMap<u32, Entity> = snapshot.entities.iter().map(|entity| (entity.id(), *entity)).collect();
+
+    let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+    let type_registry = type_registry.read();
+    let removable_components = world.get_resource::<EditorSettings>().unwrap().removable_components.clone();
+    // applying a list doesn't remove surplus elements, so the hierarchy is inserted from scratch instead
+    let hierarchy_components = [
+        TypeId::of::<Parent>(),
+        TypeId::of::<PreviousParent>(),
+        TypeId::of::<Children>(),
+    ];
+
+    for scene_entity in &snapshot.scene.entities {
+        let entity = entity_map.get(entities_by_id[&scene_entity.entity]).unwrap();
+
+        let snapshot_components: HashSet<TypeId> = scene_entity
+            .components
+            .iter()
+            .filter_map(|component| type_registry.get_with_name(component.type_name()))
+            .map(|registration| registration.type_id())
+            .collect();
+        for (type_id, remove) in &removable_components {
+            let reflectable = type_registry
+                .get(*type_id)
+                .map_or(false, |registration| registration.data::<ReflectComponent>().is_some());
+            let replace = !snapshot_components.contains(type_id) || hierarchy_components.contains(type_id);
+            if reflectable && replace && world.entity(entity).contains_type_id(*type_id) {
+                remove(world, entity);
+            }
+        }
+
+        for component in &scene_entity.components {
+            let registration = match type_registry.get_with_name(component.type_name()) {
+                Some(registration) => registration,
+                None => continue,
+            };
+            let reflect_component = match registration.data::<ReflectComponent>() {
+                Some(reflect_component) => reflect_component,
+                None => continue,
+            };
+
+            if world.entity(entity).contains_type_id(registration.type_id()) {
+                reflect_component.apply_component(world, entity, &**component);
+            } else {
+                reflect_component.add_component(world, entity, &**component);
+            }
+        }
+    }
+
+    for registration in type_registry.iter() {
+        if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+            if let Err(e) = map_entities.map_entities(world, &entity_map) {
+                warn!(
+                    "failed to restore entity references of `{}`: {}",
+                    registration.short_name(),
+                    e
+                );
+            }
+        }
+    }
+
+    for restore in snapshot.resources {
+        restore(world);
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
+use bevy::render::pipeline::PipelineDescriptor;
 use bevy::render::wireframe::WireframeConfig;
+use bevy::transform::TransformSystem;
 
 use bevy_fly_camera::FlyCameraPlugin;
 use bevy_pancam::PanCamPlugin;
@@ -7,7 +9,11 @@ use bevy_input_actionmap::ActionPlugin;
 use bevy_inspector_egui::{WorldInspectorParams, WorldInspectorPlugin};
 use bevy_mod_picking::{InteractablePickingPlugin, PickingPlugin, PickingPluginState, PickingSystem};
 
-use crate::{drag_and_drop, systems, ui, EditorAction, EditorSettings};
+use crate::{
+    bookmarks, bounds, camera, debug_draw, drag_and_drop, frustum, grid, hierarchy_axes, icons, labels, lights,
+    look_through, normals, pan_cam, play_mode, status_bar, systems, time, ui, view, DebugDraw, EditorAction, EditorSettings,
+    EditorTime, PlayMode,
+};
 
 /// See the [crate-level docs](index.html) for usage
 pub struct EditorPlugin;
@@ -15,11 +21,13 @@ pub struct EditorPlugin;
 impl Plugin for EditorPlugin {
     fn build(&self, app: &mut AppBuilder) {
         // bevy-inspector-egui
-        app.world_mut().get_resource_or_insert_with(|| WorldInspectorParams {
+        let mut world_inspector_params = app.world_mut().get_resource_or_insert_with(|| WorldInspectorParams {
             enabled: false,
             despawnable_entities: true,
             ..Default::default()
         });
+        world_inspector_params.ignore_component::<debug_draw::DebugDrawMesh>();
+        world_inspector_params.ignore_component::<icons::EditorIcon>();
         app.add_plugin(WorldInspectorPlugin::new());
 
         // bevy_mod_picking
@@ -39,10 +47,36 @@ impl Plugin for EditorPlugin {
         // bevy_input_actionmap
         app.add_plugin(ActionPlugin::<EditorAction>::default());
 
-        // resources
-        app.init_resource::<EditorState>().add_event::<ui::EditorMenuEvent>();
+        // debug drawing
+        {
+            let world = app.world_mut().cell();
+            let mut shaders = world.get_resource_mut::<Assets<Shader>>().unwrap();
+            let mut pipelines = world.get_resource_mut::<Assets<PipelineDescriptor>>().unwrap();
+            pipelines.set_untracked(
+                debug_draw::DEBUG_LINES_PIPELINE_HANDLE,
+                debug_draw::build_debug_lines_pipeline(&mut shaders),
+            );
+        }
+        app.init_resource::<DebugDraw>()
+            .add_startup_system(debug_draw::setup_debug_draw.system())
+            .add_system(debug_draw::debug_draw_layer_system.system())
+            .add_system_to_stage(CoreStage::PostUpdate, debug_draw::debug_draw_system.system());
 
-        let editor_settings = app.world_mut().get_resource_or_insert_with(EditorSettings::default);
+        // resources
+        app.init_resource::<EditorState>()
+            .init_resource::<EditorTime>()
+            .init_resource::<PlayMode>()
+            .init_resource::<bookmarks::CameraBookmarks>()
+            .init_resource::<camera::MouseLook>()
+            .init_resource::<camera::FollowSelected>()
+            .init_resource::<look_through::LookThrough>()
+            .init_resource::<bounds::MeshBounds>()
+            .init_resource::<lights::LightIconMesh>()
+            .init_resource::<frustum::CameraIconMesh>()
+            .add_event::<ui::EditorMenuEvent>();
+
+        let mut editor_settings = app.world_mut().get_resource_or_insert_with(EditorSettings::default);
+        play_mode::add_bevy_removable_components(&mut editor_settings);
         let show_wireframes = editor_settings.show_wireframes;
 
         if app.world().contains_resource::<WireframeConfig>() {
@@ -50,10 +84,27 @@ impl Plugin for EditorPlugin {
         }
 
         // systems
+        // `CoreSystem::Time` is an exclusive system, which runs before all parallel systems of the stage
+        app.add_system_to_stage(CoreStage::First, time::editor_time_system.system());
+        app.add_system_to_stage(CoreStage::First, play_mode::play_mode_system.exclusive_system());
+
         app.add_system(ui::menu_system.exclusive_system());
         app.add_system(ui::currently_inspected_system.exclusive_system());
         app.add_system(ui::handle_menu_event.system());
         app.add_system(ui::performance_panel.system());
+        app.add_system(bounds::bounding_box_system.exclusive_system());
+        app.add_system(grid::grid_system.system());
+        app.add_system(icons::icon_follow_system.system());
+        app.add_system(lights::light_icon_system.system());
+        app.add_system(lights::draw_lights_system.system());
+        app.add_system(lights::lights_panel.system());
+        app.add_system(frustum::camera_icon_system.system());
+        app.add_system(frustum::frustum_system.system());
+        app.add_system(labels::name_labels_system.exclusive_system());
+        app.add_system(normals::normals_system.system());
+        app.add_system(hierarchy_axes::hierarchy_axes_system.system());
+        app.add_system(status_bar::status_bar_system.system());
+        app.add_system(ui::camera_settings_panel.system());
 
         app.add_system(drag_and_drop::drag_and_drop_system.exclusive_system());
 
@@ -63,6 +114,24 @@ impl Plugin for EditorPlugin {
         app.add_system(systems::make_cam_flycam.system());
         app.add_system(systems::make_cam_pancam.system());
 
+        app.add_system(camera::editor_camera_system.system());
+        app.add_system(camera::viewport_picking_system.system());
+        app.add_system(camera::mouse_look_system.system().label("editor mouse look"));
+        app.add_system(camera::fly_camera_settings_system.system().after("editor mouse look"));
+        app.add_system(camera::camera_transition_system.system());
+        app.add_system(camera::orbit_center_transition_system.system());
+        app.add_system(camera::follow_selected_system.system());
+        app.add_system(bookmarks::camera_bookmark_system.system());
+        app.add_system(view::view_preset_system.system());
+        app.add_system(look_through::look_through_system.system());
+        app.add_system(pan_cam::reset_2d_view_system.system());
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            pan_cam::pan_cam_limits_system
+                .system()
+                .before(TransformSystem::TransformPropagate),
+        );
+
         app.add_system_to_stage(
             CoreStage::PostUpdate,
             systems::maintain_inspected_entities.system().after(PickingSystem::Focus),
@@ -74,12 +143,18 @@ impl Plugin for EditorPlugin {
 
 pub struct EditorState {
     pub currently_inspected: Option<Entity>,
+    /// The dedicated camera spawned by the editor in single-window mode
+    pub editor_camera: Option<Entity>,
+    /// The camera which was active before switching to the editor camera
+    pub game_camera: Option<Entity>,
 }
 
 impl Default for EditorState {
     fn default() -> Self {
         EditorState {
             currently_inspected: None,
+            editor_camera: None,
+            game_camera: None,
         }
     }
 }
@@ -8,14 +8,13 @@ use bevy::{
     },
     window::{CreateWindow, WindowDescriptor, WindowId},
 };
-use bevy_fly_camera::FlyCamera;
 use bevy_inspector_egui::bevy_egui;
 use bevy_mod_picking::PickingCameraBundle;
 use once_cell::sync::Lazy;
 
 static EDITOR_WINDOW_ID: Lazy<WindowId> = Lazy::new(WindowId::new);
 
-use crate::{EditorPlugin, EditorSettings};
+use crate::{camera::EditorCamera, EditorPlugin, EditorSettings};
 
 pub struct EditorPluginSecondWindow;
 
@@ -189,6 +188,7 @@ fn setup_pipeline(render_graph: &mut RenderGraph, active_cameras: &mut ActiveCam
 
 fn setup_second_window(
     mut commands: Commands,
+    editor_settings: Res<EditorSettings>,
     mut app_state: ResMut<State<EditorWindowState>>,
     windows: Res<Windows>,
     mut active_cameras: ResMut<ActiveCameras>,
@@ -214,13 +214,11 @@ fn setup_second_window(
             transform: Transform::from_xyz(0.0, 2.0, 10.0),
             ..Default::default()
         })
-        .insert(FlyCamera {
-            enabled: true,
-            sensitivity: 6.0,
-            only_if_mouse_down: Some(MouseButton::Left),
-            ..Default::default()
-        })
-        .insert_bundle(PickingCameraBundle::default());
+        .insert(crate::camera::fly_camera(&editor_settings.fly_camera_settings, true))
+        .insert(crate::camera::ManagedFlyCamera)
+        .insert(EditorCamera)
+        .insert_bundle(PickingCameraBundle::default())
+        .insert(crate::camera::ManagedPickingCamera);
 
     app_state.set(EditorWindowState::Done).unwrap();
 }
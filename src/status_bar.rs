@@ -0,0 +1,101 @@
+use bevy::{
+    prelude::*,
+    render::camera::{ActiveCameras, Camera, PerspectiveProjection},
+};
+use bevy_inspector_egui::{bevy_egui::EguiContext, egui};
+use bevy_mod_picking::PickingCamera;
+
+use crate::{camera, icons::EditorIcon, EditorSettings};
+
+fn format_vec3(vec: Vec3) -> String {
+    format!("{:.3}, {:.3}, {:.3}", vec.x, vec.y, vec.z)
+}
+
+/// Shows the world position under the mouse cursor along with the entity there in a panel at the bottom of the window.
+///
+/// 3D positions come from the picking raycast of the viewport camera,
+/// orthographic cameras fall back to the position on the `z = 0` plane if no mesh was hit,
+/// or on their near plane if they look along that plane.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn status_bar_system(
+    egui_context: Res<EguiContext>,
+    editor_settings: Res<EditorSettings>,
+    active_cameras: Res<ActiveCameras>,
+    windows: Res<Windows>,
+    cameras: Query<(
+        &Camera,
+        &GlobalTransform,
+        Option<&PickingCamera>,
+        Option<&PerspectiveProjection>,
+    )>,
+    icons: Query<&EditorIcon>,
+    names: Query<&Name>,
+) {
+    if !editor_settings.status_bar || !editor_settings.display_ui {
+        return;
+    }
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    let viewport_camera = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get(entity).ok().map(|(camera, ..)| camera.window)
+    });
+    let camera = viewport_camera.and_then(|entity| cameras.get(entity).ok());
+
+    let mut position = None;
+    let mut normal = None;
+    let mut hovered = None;
+    if let Some((camera, transform, picking_camera, perspective)) = camera {
+        if let Some((entity, intersection)) = picking_camera.and_then(PickingCamera::intersect_top) {
+            let hit_position = intersection.position();
+            let hit_normal = intersection.normal();
+            position = Some(Vec3::new(hit_position.x, hit_position.y, hit_position.z));
+            normal = Some(Vec3::new(hit_normal.x, hit_normal.y, hit_normal.z));
+            hovered = Some(icons.get(entity).map_or(entity, |icon| icon.target));
+        } else if perspective.is_none() {
+            let window = windows.get(camera.window);
+            let cursor = window.and_then(|window| Some((window.cursor_position()?, window)));
+            if let Some((cursor, window)) = cursor {
+                let window_size = Vec2::new(window.width(), window.height());
+                let ndc = cursor / window_size * 2.0 - Vec2::ONE;
+                let ndc_to_world = transform.compute_matrix() * camera.projection_matrix.inverse();
+                let near = ndc_to_world.project_point3(ndc.extend(0.0));
+                let far = ndc_to_world.project_point3(ndc.extend(1.0));
+                // 2D cameras sit far in front of the sprites, so their near plane isn't where the world is
+                let direction = far - near;
+                position = if direction.z.abs() > f32::EPSILON {
+                    Some(near - direction * (near.z / direction.z))
+                } else {
+                    Some(near)
+                };
+            }
+        }
+    }
+
+    egui::TopBottomPanel::bottom("editor_pls status bar").show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            match position {
+                Some(position) => {
+                    let text = format_vec3(position);
+                    ui.label(format!("Position: {}", text));
+                    if ui.small_button("Copy").clicked() {
+                        ui.output().copied_text = text;
+                    }
+                }
+                None => {
+                    ui.label("Position: -");
+                }
+            }
+            if let Some(normal) = normal {
+                ui.separator();
+                ui.label(format!("Normal: {}", format_vec3(normal)));
+            }
+            if let Some(entity) = hovered {
+                ui.separator();
+                ui.label(crate::ui::name_or_entity(names.get(entity).ok(), entity));
+            }
+        });
+    });
+}
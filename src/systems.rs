@@ -1,14 +1,23 @@
 use bevy::{
-    ecs::system::QuerySingleError,
     prelude::*,
-    render::{camera::{Camera, OrthographicProjection}, render_graph::base::camera},
+    render::{
+        camera::{ActiveCameras, Camera, OrthographicProjection},
+        render_graph::base::camera,
+    },
 };
 use bevy_fly_camera::FlyCamera;
 use bevy_mod_picking::{PickableBundle, PickableMesh, PickingCamera, PickingCameraBundle};
 use bevy_orbit_controls::OrbitCamera;
 use bevy_pancam::PanCam;
 
-use crate::{plugin::EditorState, EditorSettings};
+use crate::{
+    camera::{OrbitCenterTransition, SuspendedPickingCamera},
+    debug_draw::DebugDrawMesh,
+    icons::EditorIcon,
+    plugin::EditorState,
+    view::StoredPerspective,
+    EditorSettings, EditorTarget,
+};
 
 fn should_inspect_entity(input: &Input<KeyCode>) -> bool {
     input.pressed(KeyCode::LControl)
@@ -18,13 +27,16 @@ fn should_select_orbit_target(input: &Input<KeyCode>) -> bool {
     input.pressed(KeyCode::LAlt)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn maintain_inspected_entities(
     mut commands: Commands,
     editor_settings: ResMut<EditorSettings>,
     mut editor_state: ResMut<EditorState>,
     query: Query<(Entity, &GlobalTransform, &Interaction), Changed<Interaction>>,
-    mut orbit_camera: Query<&mut OrbitCamera>,
-    cameras: Query<(Entity, &Camera)>,
+    icons: Query<&EditorIcon>,
+    orbit_camera: Query<&OrbitCamera>,
+    picking_cameras: Query<(Entity, &PickingCamera, &Transform)>,
+    active_cameras: Res<ActiveCameras>,
     input: Res<Input<KeyCode>>,
 ) {
     if !editor_settings.click_to_inspect && !editor_settings.orbit_camera {
@@ -43,28 +55,41 @@ pub fn maintain_inspected_entities(
     };
 
     if editor_settings.click_to_inspect && should_inspect_entity(&input) {
-        if editor_state.currently_inspected == Some(entity) {
+        // clicking the icon of a light or camera inspects the light or camera itself
+        let inspected = icons.get(entity).map_or(entity, |icon| icon.target);
+        if editor_state.currently_inspected == Some(inspected) {
             editor_state.currently_inspected = None;
         } else {
-            editor_state.currently_inspected = Some(entity);
+            editor_state.currently_inspected = Some(inspected);
         }
     }
 
     if editor_settings.orbit_camera && should_select_orbit_target(&input) {
-        match orbit_camera.single_mut() {
-            Err(QuerySingleError::NoEntities(_)) => {
-                let (cam_entity, _) = cameras
-                    .iter()
-                    .find(|(_, cam)| cam.name.as_ref().map_or(false, |name| name == camera::CAMERA_3D))
-                    .unwrap();
+        // every viewport orbits around its own target, so use the active camera the entity was clicked through
+        let cam_entity = picking_cameras
+            .iter()
+            .filter(|(cam_entity, _, _)| active_cameras.iter().any(|active| active.entity == Some(*cam_entity)))
+            .find(|(_, picking_camera, _)| picking_camera.intersect_top().map(|(hit, _)| hit) == Some(entity));
+        let (cam_entity, cam_transform) = match cam_entity {
+            Some((cam_entity, _, cam_transform)) => (cam_entity, cam_transform),
+            None => return,
+        };
 
+        let target = transform.translation;
+        match orbit_camera.get(cam_entity) {
+            Ok(cam) => {
                 commands
                     .entity(cam_entity)
-                    .insert(OrbitCamera::new(10.0, transform.translation));
+                    .insert(OrbitCenterTransition::new(cam.center, target));
             }
-            Err(QuerySingleError::MultipleEntities(_)) => panic!("There are multiple orbit cameras"),
-            Ok(mut cam) => {
-                cam.center = transform.translation;
+            Err(_) => {
+                // start orbiting around a point in front of the camera so it doesn't jump, then move to the target
+                let mut cam = OrbitCamera::new(target.distance(cam_transform.translation), target);
+                crate::camera::sync_orbit_camera(&mut cam, cam_transform);
+                commands
+                    .entity(cam_entity)
+                    .insert(OrbitCenterTransition::new(cam.center, target))
+                    .insert(cam);
             }
         };
     }
@@ -75,7 +100,7 @@ pub fn maintain_inspected_entities(
 pub fn make_everything_pickable(
     editor_settings: Res<EditorSettings>,
     mut commands: Commands,
-    mut query: Query<Entity, (With<Draw>, Without<PickableMesh>, Without<Node>)>,
+    mut query: Query<Entity, (With<Draw>, Without<PickableMesh>, Without<Node>, Without<DebugDrawMesh>)>,
 ) {
     if !editor_settings.auto_pickable {
         return;
@@ -88,15 +113,18 @@ pub fn make_everything_pickable(
 pub fn make_camera_picksource(
     editor_settings: Res<EditorSettings>,
     mut commands: Commands,
-    mut query: Query<(Entity, &Camera), Without<PickingCamera>>,
+    mut query: Query<(Entity, &Camera, Option<&EditorTarget>), (Without<PickingCamera>, Without<SuspendedPickingCamera>)>,
 ) {
     if !editor_settings.auto_pickable_camera {
         return;
     }
 
-    for (entity, cam) in query.iter_mut() {
-        if cam.name.as_ref().map_or(false, |name| name == camera::CAMERA_3D) {
-            commands.entity(entity).insert_bundle(PickingCameraBundle::default());
+    for (entity, cam, target) in query.iter_mut() {
+        if target.is_some() || editor_settings.targets_camera(cam, camera::CAMERA_3D) {
+            commands
+                .entity(entity)
+                .insert_bundle(PickingCameraBundle::default())
+                .insert(crate::camera::ManagedPickingCamera);
         }
     }
 }
@@ -104,20 +132,20 @@ pub fn make_camera_picksource(
 pub fn make_cam_flycam(
     editor_settings: Res<EditorSettings>,
     mut commands: Commands,
-    mut query: Query<(Entity, &Camera), Without<FlyCamera>>,
+    mut query: Query<(Entity, &Camera, Option<&EditorTarget>), (Without<FlyCamera>, Without<OrthographicProjection>)>,
 ) {
     if !editor_settings.auto_flycam {
         return;
     }
 
-    for (entity, cam) in query.iter_mut() {
-        if cam.name.as_ref().map_or(false, |name| name == camera::CAMERA_3D) {
-            commands.entity(entity).insert(FlyCamera {
-                enabled: editor_settings.fly_camera,
-                sensitivity: 6.0,
-                only_if_mouse_down: Some(MouseButton::Left),
-                ..Default::default()
-            });
+    for (entity, cam, target) in query.iter_mut() {
+        if target.is_some() || editor_settings.targets_camera(cam, camera::CAMERA_3D) {
+            // while the editor camera is active, the game camera only gets a disabled fly camera
+            let enabled = editor_settings.fly_camera && !editor_settings.editor_camera;
+            commands
+                .entity(entity)
+                .insert(crate::camera::fly_camera(&editor_settings.fly_camera_settings, enabled))
+                .insert(crate::camera::ManagedFlyCamera);
         }
     }
 }
@@ -125,15 +153,21 @@ pub fn make_cam_flycam(
 pub fn make_cam_pancam(
     editor_settings: Res<EditorSettings>,
     mut commands: Commands,
-    mut query: Query<(Entity, &Camera), (With<OrthographicProjection>, Without<PanCam>)>,
+    mut query: Query<
+        (Entity, &Camera, &Transform, &OrthographicProjection, Option<&EditorTarget>),
+        (Without<StoredPerspective>, Without<PanCam>),
+    >,
 ) {
     if !editor_settings.auto_pancam {
         return;
     }
 
-    for (entity, cam) in query.iter_mut() {
-        if cam.name.as_ref().map_or(false, |name| name == camera::CAMERA_2D) {
-            commands.entity(entity).insert(PanCam::default());
+    for (entity, cam, transform, projection, target) in query.iter_mut() {
+        if target.is_some() || editor_settings.targets_camera(cam, camera::CAMERA_2D) {
+            commands
+                .entity(entity)
+                .insert(crate::pan_cam::pan_cam(&editor_settings.pan_cam_settings))
+                .insert(crate::pan_cam::ManagedPanCam::new(transform, projection));
         }
     }
-}
\ No newline at end of file
+}
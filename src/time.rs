@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use bevy::{ecs::schedule::ShouldRun, prelude::*};
+
+/// The range the time scale can be set to.
+pub(crate) const TIME_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.0..=4.0;
+
+/// Game time as seen through the play controls in the editor menu bar.
+///
+/// Systems that should freeze while the game is paused can either read their delta from here instead of [`Time`],
+/// or be gated behind the [`run_if_playing`] run criterion.
+/// The delta is also multiplied by the time scale, which allows slowing down or speeding up the game.
+pub struct EditorTime {
+    paused: bool,
+    step_requested: bool,
+    running: bool,
+    time_scale: f32,
+    delta: Duration,
+    elapsed: Duration,
+}
+
+impl Default for EditorTime {
+    fn default() -> Self {
+        EditorTime {
+            paused: false,
+            step_requested: false,
+            running: true,
+            time_scale: 1.0,
+            delta: Duration::default(),
+            elapsed: Duration::default(),
+        }
+    }
+}
+
+impl EditorTime {
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Pauses the game and lets it advance by exactly one frame.
+    pub fn step(&mut self) {
+        self.paused = true;
+        self.step_requested = true;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether game systems run this frame. This is also true for the single frame after [`EditorTime::step`].
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Sets the factor the game delta gets multiplied with, clamped to `0..=4`.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.clamp(*TIME_SCALE_RANGE.start(), *TIME_SCALE_RANGE.end());
+    }
+
+    /// The game delta of this frame, scaled by the time scale and zero while paused.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Total game time that has passed while not paused.
+    pub fn seconds_since_startup(&self) -> f64 {
+        self.elapsed.as_secs_f64()
+    }
+}
+
+pub(crate) fn editor_time_system(time: Res<Time>, mut editor_time: ResMut<EditorTime>) {
+    let step = std::mem::take(&mut editor_time.step_requested);
+    editor_time.running = !editor_time.paused || step;
+    editor_time.delta = if editor_time.running {
+        time.delta().mul_f32(editor_time.time_scale)
+    } else {
+        Duration::default()
+    };
+    editor_time.elapsed += editor_time.delta;
+}
+
+/// Run criterion which only runs the system while the game isn't paused in the editor.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # fn physics() {}
+/// # App::build()
+/// .add_system_set(
+///     SystemSet::new()
+///         .with_run_criteria(bevy_editor_pls::run_if_playing.system())
+///         .with_system(physics.system()),
+/// );
+/// ```
+pub fn run_if_playing(editor_time: Res<EditorTime>) -> ShouldRun {
+    if editor_time.running {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
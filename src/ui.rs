@@ -3,11 +3,14 @@ use bevy::{
     diagnostic::{Diagnostic, FrameTimeDiagnosticsPlugin},
     prelude::*,
 };
-use bevy::{diagnostic::Diagnostics, render::wireframe::WireframeConfig};
+use bevy::{
+    diagnostic::Diagnostics,
+    render::wireframe::{Wireframe, WireframeConfig},
+};
 use bevy_fly_camera::FlyCamera;
 use bevy_orbit_controls::OrbitCamera;
 
-use crate::{plugin::EditorState, EditorSettings};
+use crate::{camera, plugin::EditorState, EditorSettings, EditorTime, FlyCameraKeyLayout, PlayMode};
 use bevy_inspector_egui::{
     bevy_egui::EguiContext,
     egui::{self, menu},
@@ -18,24 +21,52 @@ use bevy_inspector_egui::{
 pub(crate) enum EditorMenuEvent {
     EnableFlyCams(bool),
     DisableOrbitCam,
+    /// Toggles the [`Wireframe`] of an entity and of the meshes among its descendants
+    ToggleWireframe(Entity),
 }
 
 pub(crate) fn handle_menu_event(
     mut commands: Commands,
     mut events: EventReader<EditorMenuEvent>,
-    mut flycam_query: Query<&mut FlyCamera>,
+    editor_settings: Res<EditorSettings>,
+    editor_state: Res<EditorState>,
+    mut flycam_query: Query<(Entity, &mut FlyCamera)>,
     orbit_cam_query: Query<Entity, With<OrbitCamera>>,
+    mesh_query: Query<Option<&Wireframe>, With<Handle<Mesh>>>,
+    children_query: Query<&Children>,
 ) {
     for event in events.iter() {
         match *event {
             EditorMenuEvent::EnableFlyCams(enabled) => {
-                for mut cam in flycam_query.iter_mut() {
-                    cam.enabled = enabled;
+                for (entity, mut cam) in flycam_query.iter_mut() {
+                    cam.enabled = enabled && camera::fly_camera_enabled(&editor_settings, &editor_state, entity);
                 }
             }
             EditorMenuEvent::DisableOrbitCam => {
                 orbit_cam_query.for_each(|entity| drop(commands.entity(entity).remove::<OrbitCamera>()))
             }
+            EditorMenuEvent::ToggleWireframe(entity) => {
+                let mut meshes = Vec::new();
+                let mut stack = vec![entity];
+                while let Some(entity) = stack.pop() {
+                    if let Ok(wireframe) = mesh_query.get(entity) {
+                        meshes.push((entity, wireframe.is_some()));
+                    }
+                    if let Ok(children) = children_query.get(entity) {
+                        stack.extend(children.iter().copied());
+                    }
+                }
+
+                // only remove the wireframes if every mesh already has one
+                let enable = meshes.iter().any(|&(_, wireframe)| !wireframe);
+                for (entity, _) in meshes {
+                    if enable {
+                        commands.entity(entity).insert(Wireframe);
+                    } else {
+                        commands.entity(entity).remove::<Wireframe>();
+                    }
+                }
+            }
         }
     }
 }
@@ -48,6 +79,8 @@ pub(crate) fn menu_system(world: &mut World) {
     let mut inspector_params = world.get_resource_mut::<WorldInspectorParams>().unwrap();
     let mut wireframe_config = world.get_resource_mut::<WireframeConfig>();
     let diagnostics = world.get_resource::<Diagnostics>().unwrap();
+    let mut editor_time = world.get_resource_mut::<EditorTime>().unwrap();
+    let mut play_mode = world.get_resource_mut::<PlayMode>().unwrap();
 
     if inspector_params.window != editor_settings.window {
         inspector_params.window = editor_settings.window;
@@ -77,11 +110,51 @@ pub(crate) fn menu_system(world: &mut World) {
                         ui.end_row();
                     }
 
+                    checkbox(ui, &mut editor_settings.normals_selected, "Normals of selected");
+                    ui.end_row();
+                    checkbox(ui, &mut editor_settings.normals_all, "Normals of all meshes");
+                    ui.end_row();
+                    if editor_settings.normals_selected || editor_settings.normals_all {
+                        ui.label("Normals length");
+                        ui.add(egui::Slider::new(&mut editor_settings.normals_length, 0.01..=2.0).logarithmic(true));
+                        ui.end_row();
+                    }
+
+                    checkbox(ui, &mut editor_settings.hierarchy_axes, "Hierarchy axes");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.show_grid, "Grid");
+                    ui.end_row();
+                    checkbox(ui, &mut editor_settings.show_world_axes, "World axes");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.show_lights, "Lights");
+                    ui.end_row();
+                    checkbox(ui, &mut editor_settings.show_camera_frustums, "Camera frustums");
+                    ui.end_row();
+                    checkbox(ui, &mut editor_settings.show_labels, "Name labels");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.bounding_boxes_selected, "Bounds of selected");
+                    ui.end_row();
+                    checkbox(ui, &mut editor_settings.bounding_boxes_pickable, "Bounds of pickables");
+                    ui.end_row();
+                    if editor_settings.bounding_box_filter.is_some() {
+                        checkbox(ui, &mut editor_settings.bounding_boxes_filtered, "Bounds of filtered");
+                        ui.end_row();
+                    }
+
                     if checkbox_changed(ui, &mut editor_settings.fly_camera, "Fly camera") {
                         menu_events.send(EditorMenuEvent::EnableFlyCams(editor_settings.fly_camera));
                     }
                     ui.end_row();
 
+                    checkbox(ui, &mut editor_settings.editor_camera, "Editor camera");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.follow_selected, "Follow selected");
+                    ui.end_row();
+
                     if checkbox_changed(ui, &mut editor_settings.orbit_camera, "Orbit camera") {
                         if !editor_settings.orbit_camera {
                             menu_events.send(EditorMenuEvent::DisableOrbitCam);
@@ -89,6 +162,15 @@ pub(crate) fn menu_system(world: &mut World) {
                     }
                     ui.end_row();
 
+                    checkbox(ui, &mut editor_settings.camera_settings_panel, "Camera settings");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.lights_panel, "Lights panel");
+                    ui.end_row();
+
+                    checkbox(ui, &mut editor_settings.status_bar, "Status bar");
+                    ui.end_row();
+
                     if frame_time_diagnostics {
                         checkbox(ui, &mut editor_settings.performance_panel, "Performance Panel");
                     }
@@ -104,10 +186,44 @@ pub(crate) fn menu_system(world: &mut World) {
                     }
                 });
             }
+
+            ui.separator();
+            play_controls(ui, &mut editor_time, &mut play_mode);
         });
     });
 }
 
+fn play_controls(ui: &mut egui::Ui, editor_time: &mut EditorTime, play_mode: &mut PlayMode) {
+    if play_mode.is_playing() {
+        if ui
+            .button("⏹ Stop")
+            .on_hover_text("Restore the world from when play was pressed")
+            .clicked()
+        {
+            play_mode.stop();
+        }
+    } else if ui.button("▶ Play").on_hover_text("Snapshot the world and play").clicked() {
+        play_mode.play();
+    }
+
+    if editor_time.is_paused() {
+        if ui.button("⏯ Resume").clicked() {
+            editor_time.resume();
+        }
+    } else if ui.button("⏸ Pause").clicked() {
+        editor_time.pause();
+    }
+    if ui.button("⏭ Step").on_hover_text("Advance the game by one frame").clicked() {
+        editor_time.step();
+    }
+
+    let mut time_scale = editor_time.time_scale();
+    let slider = egui::Slider::new(&mut time_scale, crate::time::TIME_SCALE_RANGE).text("speed");
+    if ui.add(slider).on_hover_text("Time scale of the game").changed() {
+        editor_time.set_time_scale(time_scale);
+    }
+}
+
 pub(crate) fn performance_panel(
     egui_context: Res<EguiContext>,
     mut editor_settings: ResMut<EditorSettings>,
@@ -153,6 +269,98 @@ pub(crate) fn performance_panel(
         });
 }
 
+pub(crate) fn camera_settings_panel(egui_context: Res<EguiContext>, mut editor_settings: ResMut<EditorSettings>) {
+    if !editor_settings.camera_settings_panel {
+        return;
+    }
+
+    let ctx = match egui_context.try_ctx_for_window(editor_settings.window) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    let EditorSettings {
+        camera_settings_panel,
+        fly_camera_settings: settings,
+        view_presets_orthographic,
+        pan_cam_settings,
+        ..
+    } = &mut *editor_settings;
+    egui::Window::new("Camera settings")
+        .open(camera_settings_panel)
+        .resizable(false)
+        .show(ctx, |ui| {
+            egui::Grid::new("fly camera settings").show(ui, |ui| {
+                ui.label("Speed");
+                ui.add(egui::Slider::new(&mut settings.max_speed, camera::FLY_CAMERA_SPEED_RANGE).logarithmic(true));
+                ui.end_row();
+                ui.label("Acceleration");
+                ui.add(egui::Slider::new(&mut settings.accel, 0.1..=10.0));
+                ui.end_row();
+                ui.label("Sensitivity");
+                ui.add(egui::Slider::new(&mut settings.sensitivity, 0.5..=20.0));
+                ui.end_row();
+
+                ui.label("Look while holding");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut settings.mouse_button, Some(MouseButton::Left), "Left");
+                    ui.radio_value(&mut settings.mouse_button, Some(MouseButton::Right), "Right");
+                    ui.radio_value(&mut settings.mouse_button, Some(MouseButton::Middle), "Middle");
+                    ui.radio_value(&mut settings.mouse_button, None, "Always");
+                });
+                ui.end_row();
+
+                ui.label("Scroll adjusts speed");
+                ui.checkbox(&mut settings.scroll_adjusts_speed, "");
+                ui.end_row();
+
+                ui.label("Orthographic view presets");
+                ui.checkbox(view_presets_orthographic, "");
+                ui.end_row();
+
+                ui.label("Key layout");
+                ui.horizontal(|ui| {
+                    for layout in FlyCameraKeyLayout::ALL.iter().copied() {
+                        ui.radio_value(&mut settings.key_layout, layout, format!("{:?}", layout));
+                    }
+                });
+                ui.end_row();
+            });
+
+            ui.separator();
+            egui::Grid::new("pan camera settings").show(ui, |ui| {
+                ui.label("2D zoom speed");
+                ui.add(egui::Slider::new(&mut pan_cam_settings.zoom_speed, 0.1..=4.0));
+                ui.end_row();
+                ui.label("2D min zoom scale");
+                ui.add(egui::Slider::new(&mut pan_cam_settings.min_scale, 0.01..=1.0).logarithmic(true));
+                ui.end_row();
+                ui.label("2D max zoom scale");
+                ui.add(egui::Slider::new(&mut pan_cam_settings.max_scale, 1.0..=100.0).logarithmic(true));
+                ui.end_row();
+
+                ui.label("Pan while holding");
+                ui.horizontal(|ui| {
+                    let buttons = [
+                        (MouseButton::Left, "Left"),
+                        (MouseButton::Right, "Right"),
+                        (MouseButton::Middle, "Middle"),
+                    ];
+                    for &(button, label) in buttons.iter() {
+                        let mut grabs = pan_cam_settings.grab_buttons.contains(&button);
+                        if ui.checkbox(&mut grabs, label).changed() {
+                            pan_cam_settings.grab_buttons.retain(|&grab_button| grab_button != button);
+                            if grabs {
+                                pan_cam_settings.grab_buttons.push(button);
+                            }
+                        }
+                    }
+                });
+                ui.end_row();
+            });
+        });
+}
+
 pub(crate) fn currently_inspected_system(world: &mut World) {
     let world_ptr = world as *mut _;
 
@@ -165,11 +373,19 @@ pub(crate) fn currently_inspected_system(world: &mut World) {
     let mut go_to_parent = None;
 
     let name = entity_name(world, currently_inspected);
+    // per-entity wireframes are only rendered if the `WireframePlugin` was added
+    let wireframe = world.contains_resource::<WireframeConfig>().then(|| {
+        let has_mesh = world.get::<Handle<Mesh>>(currently_inspected).is_some();
+        let has_children = world.get::<Children>(currently_inspected).is_some();
+        let enabled = world.get::<Wireframe>(currently_inspected).is_some();
+        (has_mesh || has_children, enabled)
+    });
 
     let world_cell = world.cell();
     let egui_context = world_cell.get_resource::<EguiContext>().unwrap();
     let editor_settings = world_cell.get_resource_mut::<EditorSettings>().unwrap();
     let mut editor_state = world_cell.get_resource_mut::<EditorState>().unwrap();
+    let mut menu_events = world_cell.get_resource_mut::<Events<EditorMenuEvent>>().unwrap();
 
     if !editor_settings.click_to_inspect {
         return;
@@ -205,6 +421,12 @@ pub(crate) fn currently_inspected_system(world: &mut World) {
                 });
             });
 
+            if let Some((true, enabled)) = wireframe {
+                if ui.selectable_label(enabled, "Wireframe").clicked() {
+                    menu_events.send(EditorMenuEvent::ToggleWireframe(currently_inspected));
+                }
+            }
+
             ui.style_mut().wrap = Some(false);
             let options = EntityAttributes { despawnable: true };
             currently_inspected.ui(ui, options, &context);
@@ -236,8 +458,14 @@ fn checkbox_changed(ui: &mut egui::Ui, selected: &mut bool, text: &str) -> bool
     checkbox(ui, selected, text);
     before != *selected
 }
-fn entity_name(world: &World, entity: Entity) -> String {
-    match world.get::<Name>(entity) {
+
+pub(crate) fn entity_name(world: &World, entity: Entity) -> String {
+    name_or_entity(world.get::<Name>(entity), entity)
+}
+
+/// The name of the entity, or its id if it has none.
+pub(crate) fn name_or_entity(name: Option<&Name>, entity: Entity) -> String {
+    match name {
         Some(name) => name.as_str().to_string(),
         None => format!("Entity {}", entity.id()),
     }
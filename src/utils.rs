@@ -1,7 +1,16 @@
 use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+
+use crate::EditorSettings;
 
-#[allow(unused)]
 pub fn toggle_grab_cursor(window: &mut Window) {
     window.set_cursor_lock_mode(!window.cursor_locked());
     window.set_cursor_visibility(!window.cursor_visible());
 }
+
+/// Whether a text field of the editor UI has focus, in which case key presses shouldn't trigger editor actions.
+pub(crate) fn ui_wants_keyboard_input(egui_context: &EguiContext, editor_settings: &EditorSettings) -> bool {
+    egui_context
+        .try_ctx_for_window(editor_settings.window)
+        .map_or(false, |ctx| ctx.wants_keyboard_input())
+}
@@ -0,0 +1,167 @@
+use bevy::{
+    prelude::*,
+    render::camera::{
+        ActiveCameras, Camera, CameraProjection, OrthographicProjection, PerspectiveProjection, ScalingMode, WindowOrigin,
+    },
+};
+use bevy_input_actionmap::InputMap;
+use bevy_inspector_egui::bevy_egui::EguiContext;
+use bevy_orbit_controls::OrbitCamera;
+
+use crate::{
+    camera::{self, CameraTransition},
+    utils, EditorAction, EditorSettings,
+};
+
+/// Distance of the pivot in front of cameras without an [`OrbitCamera`].
+const DEFAULT_PIVOT_DISTANCE: f32 = 10.0;
+
+/// Axis-aligned views the editor camera can snap to using [`EditorAction::SetView`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ViewPreset {
+    Top,
+    Bottom,
+    Front,
+    Back,
+    Left,
+    Right,
+}
+
+impl ViewPreset {
+    /// The direction the camera looks in and the direction which is up on screen.
+    fn orientation(self) -> (Vec3, Vec3) {
+        match self {
+            ViewPreset::Top => (-Vec3::Y, -Vec3::Z),
+            ViewPreset::Bottom => (Vec3::Y, Vec3::Z),
+            ViewPreset::Front => (-Vec3::Z, Vec3::Y),
+            ViewPreset::Back => (Vec3::Z, Vec3::Y),
+            ViewPreset::Left => (Vec3::X, Vec3::Y),
+            ViewPreset::Right => (-Vec3::X, Vec3::Y),
+        }
+    }
+}
+
+/// The projection a 3D camera had before the editor switched it to an orthographic one.
+pub(crate) struct StoredPerspective(PerspectiveProjection);
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn view_preset_system(
+    mut commands: Commands,
+    input: Res<InputMap<EditorAction>>,
+    editor_settings: Res<EditorSettings>,
+    egui_context: Res<EguiContext>,
+    active_cameras: Res<ActiveCameras>,
+    windows: Res<Windows>,
+    mut cameras: Query<(
+        &mut Camera,
+        &Transform,
+        Option<&PerspectiveProjection>,
+        Option<&OrthographicProjection>,
+        Option<&StoredPerspective>,
+        Option<&OrbitCamera>,
+    )>,
+) {
+    // ctrl + numpad also activates the binding without ctrl, so the opposite views are checked first
+    let preset = [
+        ViewPreset::Bottom,
+        ViewPreset::Back,
+        ViewPreset::Left,
+        ViewPreset::Top,
+        ViewPreset::Front,
+        ViewPreset::Right,
+    ]
+    .iter()
+    .copied()
+    .find(|&preset| input.just_active(EditorAction::SetView(preset)));
+    let toggle_orthographic = input.just_active(EditorAction::ToggleOrthographic);
+    if preset.is_none() && !toggle_orthographic || utils::ui_wants_keyboard_input(&egui_context, &editor_settings) {
+        return;
+    }
+
+    let camera_entity = camera::viewport_camera(&editor_settings, &active_cameras, |entity| {
+        cameras.get_mut(entity).ok().map(|(camera, ..)| camera.window)
+    });
+    let camera_entity = match camera_entity {
+        Some(camera_entity) => camera_entity,
+        None => return,
+    };
+    let (mut camera, transform, perspective, orthographic_projection, stored_perspective, orbit_camera) =
+        cameras.get_mut(camera_entity).unwrap();
+    // 2D cameras only make sense looking along -Z
+    if orthographic_projection.is_some() && stored_perspective.is_none() {
+        return;
+    }
+
+    let (pivot, distance) = match orbit_camera {
+        Some(orbit_camera) => (orbit_camera.center, orbit_camera.distance),
+        None => {
+            let forward = transform.rotation * -Vec3::Z;
+            (
+                transform.translation + forward * DEFAULT_PIVOT_DISTANCE,
+                DEFAULT_PIVOT_DISTANCE,
+            )
+        }
+    };
+
+    if let Some(preset) = preset {
+        let (direction, up) = preset.orientation();
+        let target = Transform {
+            translation: pivot - direction * distance,
+            ..*transform
+        }
+        .looking_at(pivot, up);
+        commands
+            .entity(camera_entity)
+            .insert(CameraTransition::new(*transform, None, target, None));
+    }
+
+    // 2D cameras always stay orthographic
+    let is_orthographic = stored_perspective.is_some();
+    let want_orthographic = match preset {
+        _ if toggle_orthographic => !is_orthographic,
+        Some(_) if editor_settings.view_presets_orthographic => true,
+        _ => is_orthographic,
+    };
+    if want_orthographic == is_orthographic {
+        return;
+    }
+    let window = windows.get(camera.window);
+
+    match (perspective, orthographic_projection, stored_perspective) {
+        (Some(perspective), None, None) if want_orthographic => {
+            // keep the size of objects at the pivot the same as in the perspective view
+            let mut projection = OrthographicProjection {
+                near: 0.0,
+                far: perspective.far,
+                window_origin: WindowOrigin::Center,
+                scaling_mode: ScalingMode::FixedVertical,
+                scale: distance * (perspective.fov / 2.0).tan(),
+                ..Default::default()
+            };
+            if let Some(window) = window {
+                projection.update(window.width(), window.height());
+            }
+            camera.projection_matrix = projection.get_projection_matrix();
+
+            commands
+                .entity(camera_entity)
+                .remove::<PerspectiveProjection>()
+                .insert(projection)
+                .insert(StoredPerspective(perspective.clone()));
+        }
+        (None, Some(_), Some(StoredPerspective(perspective))) if !want_orthographic => {
+            let mut projection = perspective.clone();
+            if let Some(window) = window {
+                projection.update(window.width(), window.height());
+            }
+            camera.projection_matrix = projection.get_projection_matrix();
+
+            commands
+                .entity(camera_entity)
+                .remove::<OrthographicProjection>()
+                .remove::<StoredPerspective>()
+                .insert(projection);
+        }
+        _ => {}
+    }
+}